@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::state::Side;
+use crate::errors::BetError;
+
+// Atestações de resultado assinadas off-chain (ex.: servidor do jogo).
+// A assinatura não é verificada aqui: a transação inclui, imediatamente antes,
+// uma instrução do programa Ed25519, que o runtime executa e rejeita se a
+// assinatura for inválida. Aqui só conferimos que essa instrução cobre
+// exatamente a chave e a mensagem esperadas.
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// Mensagem assinada: `bet (32) || winner_side (1: 0 = A, 1 = B) || timestamp (i64 LE)`.
+pub fn attestation_message(bet: &Pubkey, winner_side: Side, timestamp: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 + 1 + 8);
+    message.extend_from_slice(bet.as_ref());
+    message.push(match winner_side {
+        Side::A => 0,
+        Side::B => 1,
+    });
+    message.extend_from_slice(&timestamp.to_le_bytes());
+    message
+}
+
+/// Confere que a instrução anterior é uma verificação Ed25519 de `message`
+/// assinada por `signer`, com todos os dados embutidos na própria instrução.
+pub fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, BetError::InvalidAttestation);
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        BetError::InvalidAttestation
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN && data[0] == 1,
+        BetError::InvalidAttestation
+    );
+
+    let read_u16 = |offset: usize| {
+        let start = SIGNATURE_OFFSETS_START + offset;
+        u16::from_le_bytes([data[start], data[start + 1]])
+    };
+    let signature_offset = read_u16(0) as usize;
+    let signature_ix_index = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+
+    // u16::MAX = dados na própria instrução Ed25519
+    require!(
+        signature_ix_index == u16::MAX
+            && pubkey_ix_index == u16::MAX
+            && message_ix_index == u16::MAX,
+        BetError::InvalidAttestation
+    );
+    require!(
+        data.len() >= signature_offset + SIGNATURE_LEN
+            && data.len() >= pubkey_offset + PUBKEY_LEN
+            && data.len() >= message_offset + message_size,
+        BetError::InvalidAttestation
+    );
+
+    require!(
+        &data[pubkey_offset..pubkey_offset + PUBKEY_LEN] == signer.as_ref(),
+        BetError::InvalidAttestation
+    );
+    require!(
+        &data[message_offset..message_offset + message_size] == message,
+        BetError::InvalidAttestation
+    );
+
+    Ok(())
+}
@@ -52,4 +52,178 @@ pub enum BetError {
 
     #[msg("Invalid support position")]
     InvalidSupportPosition,
+
+    #[msg("Bet cannot be cancelled")]
+    CannotCancel,
+
+    #[msg("Bet is not cancelled")]
+    BetNotCancelled,
+
+    #[msg("Nothing to refund")]
+    NothingToRefund,
+
+    #[msg("Resolution deadline has not passed")]
+    ResolveDeadlineNotReached,
+
+    #[msg("Principal already claimed")]
+    PrincipalAlreadyClaimed,
+
+    #[msg("Invalid spread recipient")]
+    InvalidRecipient,
+
+    #[msg("Spread share already claimed")]
+    SpreadAlreadyClaimed,
+
+    #[msg("Bet is not fully settled")]
+    BetNotSettled,
+
+    #[msg("Support position not claimed")]
+    PositionNotClaimed,
+
+    #[msg("Invalid payer")]
+    InvalidPayer,
+
+    #[msg("Token accounts are required for token bets")]
+    MissingTokenAccounts,
+
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Invalid protocol treasury")]
+    InvalidTreasury,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid outcome")]
+    InvalidOutcome,
+
+    #[msg("Invalid allowlist")]
+    InvalidAllowlist,
+
+    #[msg("Bet is not an open challenge")]
+    NotOpenChallenge,
+
+    #[msg("Not allowed to accept this challenge")]
+    NotAllowedToAccept,
+
+    #[msg("Invalid team roster")]
+    InvalidRoster,
+
+    #[msg("Invalid tournament bracket")]
+    InvalidBracket,
+
+    #[msg("Invalid tournament")]
+    InvalidTournament,
+
+    #[msg("Match players are not decided yet")]
+    MatchNotReady,
+
+    #[msg("Match already registered")]
+    MatchAlreadyRegistered,
+
+    #[msg("Tournament matches need a winner")]
+    TournamentMatchNeedsWinner,
+
+    #[msg("Tournament has no champion yet")]
+    TournamentNotFinished,
+
+    #[msg("Prize already claimed")]
+    PrizeAlreadyClaimed,
+
+    #[msg("Invalid series length")]
+    InvalidSeries,
+
+    #[msg("Bet is a series; record each game instead")]
+    SeriesInProgress,
+
+    #[msg("Bet is not a series")]
+    NotSeries,
+
+    #[msg("Invalid dispute terms")]
+    InvalidDisputeTerms,
+
+    #[msg("Result is not final yet")]
+    ResultNotFinal,
+
+    #[msg("Dispute window is closed")]
+    DisputeWindowClosed,
+
+    #[msg("Result already disputed")]
+    DisputeAlreadyOpen,
+
+    #[msg("No open dispute")]
+    NoOpenDispute,
+
+    #[msg("Only duelists and bettors can dispute")]
+    NotEligibleToDispute,
+
+    #[msg("Invalid arbiter committee")]
+    InvalidCommittee,
+
+    #[msg("Bet is resolved by its arbiter committee")]
+    CommitteeResolution,
+
+    #[msg("Committee member already voted")]
+    AlreadyVoted,
+
+    #[msg("Arbiter bond has not been posted")]
+    ArbiterBondNotPosted,
+
+    #[msg("Arbiter bond already posted")]
+    ArbiterBondAlreadyPosted,
+
+    #[msg("Arbiter fee is paid out with the bond release")]
+    ArbiterFeeWithBond,
+
+    #[msg("Arbiter bond cannot be released")]
+    CannotReleaseBond,
+
+    #[msg("Arbiter bond cannot be slashed")]
+    CannotSlashBond,
+
+    #[msg("Invalid oracle terms")]
+    InvalidOracleTerms,
+
+    #[msg("Bet is resolved by its price oracle")]
+    OracleResolution,
+
+    #[msg("Invalid price feed account")]
+    InvalidPriceFeed,
+
+    #[msg("Oracle price is stale or not trading")]
+    StalePrice,
+
+    #[msg("Oracle price confidence too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Invalid attestor")]
+    InvalidAttestor,
+
+    #[msg("Bet is resolved by a signed attestation")]
+    AttestorResolution,
+
+    #[msg("Missing or invalid Ed25519 attestation")]
+    InvalidAttestation,
+
+    #[msg("Arbiter profile account required")]
+    ArbiterProfileRequired,
+
+    #[msg("Invalid arbiter profile")]
+    InvalidArbiterProfile,
+
+    #[msg("Arbiter track record below the bet's requirement")]
+    ArbiterTrackRecordTooShort,
+
+    #[msg("Arbiter cannot be reassigned on this bet")]
+    CannotReassignArbiter,
+
+    #[msg("Arbiter timeout not reached yet")]
+    ArbiterTimeoutNotReached,
+
+    #[msg("Tournament already has a champion")]
+    TournamentFinished,
 }
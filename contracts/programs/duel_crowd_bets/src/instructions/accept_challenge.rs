@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus, ProtocolConfig};
+use crate::errors::BetError;
+use crate::vault::deposit_to_bet;
+use crate::instructions::deposit_participant::ParticipantDeposited;
+
+#[derive(Accounts)]
+pub struct AcceptChallenge<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub challenger_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// O primeiro a aceitar um desafio aberto vira `user_b` e já deposita o stake.
+pub fn handler(ctx: Context<AcceptChallenge>) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenger_key = ctx.accounts.challenger.key();
+
+    // Validações
+    let stake;
+    {
+        require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+
+        let bet = &mut ctx.accounts.bet;
+
+        require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+        require!(bet.is_open_challenge(), BetError::NotOpenChallenge);
+        require!(
+            clock.unix_timestamp < bet.deadline_duel,
+            BetError::DeadlinePassed
+        );
+        require!(
+            challenger_key != bet.user_a
+                && challenger_key != bet.arbiter
+                && challenger_key != bet.fallback_arbiter,
+            BetError::InvalidParticipant
+        );
+        require!(
+            bet.allowlist.is_empty() || bet.allowlist.contains(&challenger_key),
+            BetError::NotAllowedToAccept
+        );
+
+        bet.user_b = challenger_key;
+        bet.user_b_deposited = true;
+
+        stake = bet.stake_b;
+    }
+
+    // Transfer stake to bet PDA (ou vault, em bets de token)
+    let received = deposit_to_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.challenger,
+        ctx.accounts.challenger_token.as_ref(),
+        stake,
+    )?;
+
+    ctx.accounts.bet.principal_b = received;
+
+    emit!(ChallengeAccepted {
+        bet: ctx.accounts.bet.key(),
+        user_b: challenger_key,
+    });
+
+    emit!(ParticipantDeposited {
+        bet: ctx.accounts.bet.key(),
+        participant: challenger_key,
+        amount: received,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ChallengeAccepted {
+    pub bet: Pubkey,
+    pub user_b: Pubkey,
+}
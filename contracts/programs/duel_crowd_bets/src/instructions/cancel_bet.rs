@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct CancelBet<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+pub fn handler(ctx: Context<CancelBet>) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações: qualquer um pode cancelar se o duelo não foi fundado a tempo
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp >= bet.deadline_duel,
+        BetError::CannotCancel
+    );
+    require!(
+        !(bet.user_a_deposited && bet.user_b_deposited),
+        BetError::CannotCancel
+    );
+    // O mercado do campeão não tem duelo próprio; sem resolução, expira
+    require!(!bet.is_champion_market(), BetError::CannotCancel);
+
+    bet.status = BetStatus::Cancelled;
+
+    emit!(BetCancelled {
+        bet: ctx.accounts.bet.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BetCancelled {
+    pub bet: Pubkey,
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::{Bet, BetStatus, SupportPosition};
 use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+use crate::instructions::refund_support::SupportRefunded;
 
 #[derive(Accounts)]
 pub struct ClaimSupport<'info> {
@@ -11,9 +14,8 @@ pub struct ClaimSupport<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
@@ -25,25 +27,41 @@ pub struct ClaimSupport<'info> {
             b"support",
             bet.key().as_ref(),
             bettor.key().as_ref(),
-            &[match support_position.side {
-                crate::state::Side::A => 0,
-                crate::state::Side::B => 1,
-            }],
+            &[support_position.outcome],
         ],
         bump = support_position.bump
     )]
     pub support_position: Account<'info, SupportPosition>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bettor_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<ClaimSupport>) -> Result<()> {
     let bet = &ctx.accounts.bet;
     let support_position = &mut ctx.accounts.support_position;
+    let clock = Clock::get()?;
 
     // Validações
     require!(
-        bet.status == BetStatus::Resolved,
+        matches!(
+            bet.status,
+            BetStatus::Resolved | BetStatus::Expired | BetStatus::Draw
+        ),
         BetError::BetNotResolved
     );
+    require!(
+        bet.is_result_final(clock.unix_timestamp),
+        BetError::ResultNotFinal
+    );
     require!(!support_position.claimed, BetError::AlreadyClaimed);
     require!(
         support_position.bet == ctx.accounts.bet.key(),
@@ -54,10 +72,66 @@ pub fn handler(ctx: Context<ClaimSupport>) -> Result<()> {
         BetError::InvalidSupportPosition
     );
 
-    let winner_side = bet.winner_side.ok_or(BetError::BetNotResolved)?;
+    // A posição deixa de contar como aberta em qualquer desfecho abaixo
+    ctx.accounts.bet.open_positions = ctx.accounts.bet.open_positions
+        .checked_sub(1)
+        .ok_or(BetError::ArithmeticOverflow)?;
+    let bet = &ctx.accounts.bet;
+
+    // Árbitro não resolveu a tempo: devolver o valor bruto, fee incluído
+    if bet.status == BetStatus::Expired {
+        let amount = support_position.gross_amount;
+
+        pay_from_bet(
+            &ctx.accounts.bet,
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.bettor.to_account_info(),
+            ctx.accounts.bettor_token.as_ref(),
+            amount,
+        )?;
+
+        support_position.claimed = true;
+
+        emit!(SupportRefunded {
+            bet: ctx.accounts.bet.key(),
+            bettor: ctx.accounts.bettor.key(),
+            amount,
+        });
+
+        return Ok(());
+    }
+
+    // Empate, ou ninguém apoiou o vencedor: devolver o valor líquido apostado
+    if bet.refunds_crowd() {
+        let payout = support_position.net_amount;
+
+        pay_from_bet(
+            &ctx.accounts.bet,
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.bettor.to_account_info(),
+            ctx.accounts.bettor_token.as_ref(),
+            payout,
+        )?;
 
-    // Se apostou no lado perdedor, apenas marcar como claimed
-    if support_position.side != winner_side {
+        support_position.claimed = true;
+
+        emit!(SupportClaimed {
+            bet: ctx.accounts.bet.key(),
+            bettor: ctx.accounts.bettor.key(),
+            payout,
+        });
+
+        return Ok(());
+    }
+
+    let winning_outcome = bet.winning_outcome.ok_or(BetError::BetNotResolved)?;
+
+    // Se apostou em outro outcome, apenas marcar como claimed
+    if support_position.outcome != winning_outcome {
         support_position.claimed = true;
 
         emit!(SupportClaimed {
@@ -69,32 +143,26 @@ pub fn handler(ctx: Context<ClaimSupport>) -> Result<()> {
         return Ok(());
     }
 
-    // Apostou no lado vencedor - calcular payout
-    let (s_win, s_lose) = match winner_side {
-        crate::state::Side::A => (bet.net_support_a, bet.net_support_b),
-        crate::state::Side::B => (bet.net_support_b, bet.net_support_a),
-    };
+    // Apostou no outcome vencedor - calcular payout
+    let s_win = bet.net_support[winning_outcome as usize];
+    let torcida_pool = bet.total_crowd_pool().ok_or(BetError::ArithmeticOverflow)?;
 
-    let torcida_pool = s_win
-        .checked_add(s_lose)
+    // payout = user_net_amount * torcida_pool / s_win (s_win > 0: senão refunds_crowd)
+    let payout = (support_position.net_amount as u128)
+        .checked_mul(torcida_pool as u128)
+        .and_then(|v| v.checked_div(s_win as u128))
+        .and_then(|v| u64::try_from(v).ok())
         .ok_or(BetError::ArithmeticOverflow)?;
 
-    // payout = user_net_amount * torcida_pool / s_win
-    let payout = if s_win > 0 {
-        (support_position.net_amount as u128)
-            .checked_mul(torcida_pool as u128)
-            .and_then(|v| v.checked_div(s_win as u128))
-            .and_then(|v| u64::try_from(v).ok())
-            .ok_or(BetError::ArithmeticOverflow)?
-    } else {
-        0
-    };
-
-    if payout > 0 {
-        // Transfer do PDA para o bettor
-        **ctx.accounts.bet.to_account_info().try_borrow_mut_lamports()? -= payout;
-        **ctx.accounts.bettor.to_account_info().try_borrow_mut_lamports()? += payout;
-    }
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.bettor.to_account_info(),
+        ctx.accounts.bettor_token.as_ref(),
+        payout,
+    )?;
 
     support_position.claimed = true;
 
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::Tournament;
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct ClaimTournamentPrize<'info> {
+    #[account(mut)]
+    pub champion: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"tournament",
+            tournament.organizer.as_ref(),
+            tournament.tournament_id.to_le_bytes().as_ref(),
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+pub fn handler(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+
+    // Validações
+    let champion = tournament.champion.ok_or(BetError::TournamentNotFinished)?;
+    require!(
+        ctx.accounts.champion.key() == champion,
+        BetError::InvalidWinner
+    );
+    require!(!tournament.prize_claimed, BetError::PrizeAlreadyClaimed);
+
+    tournament.prize_claimed = true;
+    let amount = tournament.prize_pool;
+
+    // Transfer do PDA do torneio para o campeão
+    **tournament.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.champion.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(TournamentPrizeClaimed {
+        tournament: tournament.key(),
+        champion,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentPrizeClaimed {
+    pub tournament: Pubkey,
+    pub champion: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::Bet;
+use crate::errors::BetError;
+use crate::vault::{close_vault, pay_from_bet};
+
+#[derive(Accounts)]
+pub struct CloseBet<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Original rent payer, validated against `bet.payer`
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub payer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CloseBet>) -> Result<()> {
+    let bet = &ctx.accounts.bet;
+
+    // Validações: rent (e qualquer resto de arredondamento) volta para quem pagou
+    require!(
+        ctx.accounts.payer.key() == bet.payer,
+        BetError::InvalidPayer
+    );
+    require!(bet.is_settled(), BetError::BetNotSettled);
+
+    // Bets de token: varrer o resto do vault para o payer e fechar o vault
+    if bet.is_token_bet() {
+        let (vault, token_program) = match (&ctx.accounts.vault, &ctx.accounts.token_program) {
+            (Some(vault), Some(token_program)) => (vault, token_program),
+            _ => return err!(BetError::MissingTokenAccounts),
+        };
+        let payer_info = ctx.accounts.payer.to_account_info();
+
+        pay_from_bet(
+            bet,
+            ctx.accounts.mint.as_ref(),
+            Some(vault),
+            Some(token_program),
+            &payer_info,
+            ctx.accounts.payer_token.as_ref(),
+            vault.amount,
+        )?;
+        close_vault(bet, vault, token_program, &payer_info)?;
+    }
+
+    emit!(BetClosed {
+        bet: ctx.accounts.bet.key(),
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BetClosed {
+    pub bet: Pubkey,
+    pub payer: Pubkey,
+}
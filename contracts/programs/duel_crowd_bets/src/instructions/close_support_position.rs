@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, SupportPosition};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct CloseSupportPosition<'info> {
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(
+        mut,
+        close = bettor,
+        seeds = [
+            b"support",
+            bet.key().as_ref(),
+            bettor.key().as_ref(),
+            &[support_position.outcome],
+        ],
+        bump = support_position.bump
+    )]
+    pub support_position: Account<'info, SupportPosition>,
+}
+
+pub fn handler(ctx: Context<CloseSupportPosition>) -> Result<()> {
+    let support_position = &ctx.accounts.support_position;
+
+    // Validações: só depois de sacar (ou ser reembolsado) o rent volta ao apostador
+    require!(support_position.claimed, BetError::PositionNotClaimed);
+    require!(
+        support_position.bettor == ctx.accounts.bettor.key(),
+        BetError::InvalidSupportPosition
+    );
+
+    emit!(SupportPositionClosed {
+        bet: ctx.accounts.bet.key(),
+        bettor: ctx.accounts.bettor.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SupportPositionClosed {
+    pub bet: Pubkey,
+    pub bettor: Pubkey,
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::state::{Bet, BetStatus};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    ArbiterProfile, Bet, BetStatus, CreateBetParams, DisputeStatus, PriceComparison,
+    ProtocolConfig, MAX_ALLOWLIST, MAX_OUTCOMES,
+};
 use crate::errors::BetError;
 
 #[derive(Accounts)]
-#[instruction(user_a: Pubkey, user_b: Pubkey, arbiter: Pubkey)]
+#[instruction(user_a: Pubkey, user_b: Pubkey, arbiter: Pubkey, bet_id: u64)]
 pub struct CreateBet<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -14,17 +18,46 @@ pub struct CreateBet<'info> {
         space = Bet::LEN,
         seeds = [
             b"bet",
-            arbiter.as_ref(),
             user_a.as_ref(),
-            user_b.as_ref(),
+            bet_id.to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub bet: Account<'info, Bet>,
 
-    /// CHECK: Protocol treasury address
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Protocol treasury address, validated against `config.treasury`
     pub protocol_treasury: UncheckedAccount<'info>,
 
+    // Perfil do árbitro: lido para `arbiter_requirement` e, com a assinatura
+    // dele, rastreado pelo bet
+    #[account(
+        seeds = [b"arbiter_profile", arbiter.as_ref()],
+        bump = arbiter_profile.bump
+    )]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+
+    #[account(address = arbiter)]
+    pub arbiter_signer: Option<Signer<'info>>,
+
+    // Somente para bets em SPL token
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"vault", bet.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = bet,
+        token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -33,17 +66,31 @@ pub fn handler(
     user_a: Pubkey,
     user_b: Pubkey,
     arbiter: Pubkey,
-    stake_lamports: u64,
-    deadline_duel: i64,
-    deadline_crowd: i64,
-    resolve_ts: i64,
-    spread_bps: u16,
-    creator_share_bps: u16,
-    arbiter_share_bps: u16,
-    protocol_share_bps: u16,
+    bet_id: u64,
+    params: CreateBetParams,
 ) -> Result<()> {
+    let CreateBetParams {
+        stake_a,
+        stake_b,
+        outcome_count,
+        deadline_duel,
+        deadline_crowd,
+        resolve_ts,
+        resolve_deadline,
+        spread_bps,
+        creator_share_bps,
+        arbiter_share_bps,
+        protocol_share_bps,
+        allowlist,
+        arbiter_requirement,
+    } = params;
+
     // Validações
-    require!(stake_lamports > 0, BetError::InvalidStakeAmount);
+    require!(stake_a > 0 && stake_b > 0, BetError::InvalidStakeAmount);
+    require!(
+        outcome_count >= 2 && outcome_count as usize <= MAX_OUTCOMES,
+        BetError::InvalidOutcome
+    );
     require!(
         deadline_duel < deadline_crowd, 
         BetError::InvalidDeadlines
@@ -52,7 +99,35 @@ pub fn handler(
         resolve_ts >= deadline_crowd,
         BetError::InvalidDeadlines
     );
+    require!(
+        resolve_deadline > resolve_ts,
+        BetError::InvalidDeadlines
+    );
     require!(spread_bps > 0, BetError::InvalidFeeConfig);
+
+    // user_b = Pubkey::default() abre o desafio para quem chamar accept_challenge;
+    // a allowlist (opcional) só faz sentido nesse modo
+    require!(allowlist.len() <= MAX_ALLOWLIST, BetError::InvalidAllowlist);
+    require!(
+        allowlist.is_empty() || user_b == Pubkey::default(),
+        BetError::InvalidAllowlist
+    );
+
+    // Limites globais da ProtocolConfig
+    let config = &ctx.accounts.config;
+    require!(!config.paused, BetError::ProgramPaused);
+    require!(
+        ctx.accounts.protocol_treasury.key() == config.treasury,
+        BetError::InvalidTreasury
+    );
+    require!(
+        spread_bps >= config.min_spread_bps && spread_bps <= config.max_spread_bps,
+        BetError::InvalidFeeConfig
+    );
+    require!(
+        protocol_share_bps >= config.min_protocol_share_bps,
+        BetError::InvalidFeeConfig
+    );
     require!(
         creator_share_bps
             .checked_add(arbiter_share_bps)
@@ -60,19 +135,53 @@ pub fn handler(
             == Some(10_000),
         BetError::InvalidFeeConfig
     );
+    require!(
+        ctx.accounts.mint.is_some() == ctx.accounts.vault.is_some(),
+        BetError::MissingTokenAccounts
+    );
+
+    // Exigência de histórico do árbitro (opcional)
+    if let Some(requirement) = arbiter_requirement {
+        let profile = ctx.accounts.arbiter_profile
+            .as_ref()
+            .ok_or(BetError::ArbiterProfileRequired)?;
+        require!(
+            profile.meets(&requirement),
+            BetError::ArbiterTrackRecordTooShort
+        );
+    }
+    // Só o próprio árbitro aceita ter prazos perdidos registrados no perfil
+    let arbiter_profile = match (&ctx.accounts.arbiter_profile, &ctx.accounts.arbiter_signer) {
+        (Some(profile), Some(_)) => profile.key(),
+        _ => Pubkey::default(),
+    };
+
+    let mint = ctx.accounts.mint
+        .as_ref()
+        .map(|mint| mint.key())
+        .unwrap_or_default();
 
     let bet = &mut ctx.accounts.bet;
     bet.user_a = user_a;
     bet.user_b = user_b;
+    bet.allowlist = allowlist;
     bet.arbiter = arbiter;
-    bet.stake_lamports = stake_lamports;
+    bet.bet_id = bet_id;
+    bet.mint = mint;
+    bet.stake_a = stake_a;
+    bet.stake_b = stake_b;
     bet.user_a_deposited = false;
     bet.user_b_deposited = false;
+    bet.principal_a = 0;
+    bet.principal_b = 0;
+    bet.team_a = Vec::new();
+    bet.team_b = Vec::new();
     bet.deadline_duel = deadline_duel;
     bet.deadline_crowd = deadline_crowd;
     bet.resolve_ts = resolve_ts;
-    bet.net_support_a = 0;
-    bet.net_support_b = 0;
+    bet.resolve_deadline = resolve_deadline;
+    bet.outcome_count = outcome_count;
+    bet.net_support = [0; MAX_OUTCOMES];
     bet.spread_pool_creators = 0;
     bet.spread_pool_arbiter = 0;
     bet.spread_pool_protocol = 0;
@@ -82,7 +191,36 @@ pub fn handler(
     bet.protocol_share_bps = protocol_share_bps;
     bet.status = BetStatus::Open;
     bet.winner_side = None;
+    bet.winning_outcome = None;
+    bet.series_length = 0;
+    bet.wins_a = 0;
+    bet.wins_b = 0;
+    bet.fallback_arbiter = Pubkey::default();
+    bet.dispute_window = 0;
+    bet.dispute_bond = 0;
+    bet.dispute_ends_at = 0;
+    bet.disputer = Pubkey::default();
+    bet.dispute_bond_posted = 0;
+    bet.dispute_status = DisputeStatus::None;
+    bet.committee = Vec::new();
+    bet.committee_threshold = 0;
+    bet.oracle_feed = Pubkey::default();
+    bet.strike_price = 0;
+    bet.strike_expo = 0;
+    bet.price_comparison = PriceComparison::Above;
+    bet.max_price_age = 0;
+    bet.max_confidence_bps = 0;
+    bet.attestor = Pubkey::default();
+    bet.arbiter_bond = 0;
+    bet.arbiter_bond_posted = false;
+    bet.arbiter_bond_settled = false;
+    bet.settlement_claims = 0;
+    bet.open_positions = 0;
+    bet.tournament = Pubkey::default();
+    bet.arbiter_profile = arbiter_profile;
+    bet.match_index = 0;
     bet.protocol_treasury = ctx.accounts.protocol_treasury.key();
+    bet.payer = ctx.accounts.payer.key();
     bet.bump = ctx.bumps.bet;
 
     emit!(BetCreated {
@@ -90,7 +228,10 @@ pub fn handler(
         user_a,
         user_b,
         arbiter,
-        stake_lamports,
+        bet_id,
+        mint,
+        stake_a,
+        stake_b,
     });
 
     Ok(())
@@ -102,5 +243,8 @@ pub struct BetCreated {
     pub user_a: Pubkey,
     pub user_b: Pubkey,
     pub arbiter: Pubkey,
-    pub stake_lamports: u64,
+    pub bet_id: u64,
+    pub mint: Pubkey,
+    pub stake_a: u64,
+    pub stake_b: u64,
 }
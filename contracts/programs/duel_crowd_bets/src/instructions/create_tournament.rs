@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{Tournament, MAX_BRACKET_SIZE};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+#[instruction(tournament_id: u64)]
+pub struct CreateTournament<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = Tournament::LEN,
+        seeds = [
+            b"tournament",
+            organizer.key().as_ref(),
+            tournament_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTournament>,
+    tournament_id: u64,
+    arbiter: Pubkey,
+    players: Vec<Pubkey>,
+) -> Result<()> {
+    // Validações: bracket completo (2, 4 ou 8 jogadores), sem repetidos
+    let count = players.len();
+    require!(
+        (2..=MAX_BRACKET_SIZE).contains(&count) && count.is_power_of_two(),
+        BetError::InvalidBracket
+    );
+    for (i, player) in players.iter().enumerate() {
+        require!(
+            *player != Pubkey::default() && !players[i + 1..].contains(player),
+            BetError::InvalidBracket
+        );
+    }
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.organizer = ctx.accounts.organizer.key();
+    tournament.tournament_id = tournament_id;
+    tournament.arbiter = arbiter;
+    tournament.players = players;
+    tournament.matches = vec![Pubkey::default(); count - 1];
+    tournament.advancing = vec![Pubkey::default(); count - 1];
+    tournament.champion = None;
+    tournament.champion_market = Pubkey::default();
+    tournament.prize_pool = 0;
+    tournament.prize_claimed = false;
+    tournament.bump = ctx.bumps.tournament;
+
+    emit!(TournamentCreated {
+        tournament: tournament.key(),
+        organizer: tournament.organizer,
+        tournament_id,
+        arbiter,
+        players: tournament.players.clone(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TournamentCreated {
+    pub tournament: Pubkey,
+    pub organizer: Pubkey,
+    pub tournament_id: u64,
+    pub arbiter: Pubkey,
+    pub players: Vec<Pubkey>,
+}
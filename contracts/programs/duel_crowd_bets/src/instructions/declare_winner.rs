@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{Bet, BetStatus, Side};
+use crate::state::{ArbiterProfile, Bet, BetStatus, Side, Tournament};
 use crate::errors::BetError;
 
 #[derive(Accounts)]
@@ -10,48 +10,154 @@ pub struct DeclareWinner<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
     pub bet: Account<'info, Bet>,
+
+    // Obrigatório quando o Bet é um match de torneio
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
 }
 
-pub fn handler(ctx: Context<DeclareWinner>, winner_side: Side) -> Result<()> {
-    let bet = &mut ctx.accounts.bet;
-    let clock = Clock::get()?;
+/// `outcome = None` declara empate: cada duelista e cada apostador
+/// recupera o que colocou (líquido de fee), e o spread segue distribuído
+/// normalmente via `withdraw_spread`. Os outcomes 0 e 1 correspondem aos
+/// duelistas A e B; outcomes extras só pagam o mercado da torcida e, no
+/// duelo, devolvem o stake de cada duelista.
+pub fn handler(ctx: Context<DeclareWinner>, outcome: Option<u8>) -> Result<()> {
+    let bet = &ctx.accounts.bet;
 
     // Validações
-    require!(
-        ctx.accounts.arbiter.key() == bet.arbiter,
-        BetError::InvalidArbiter
-    );
+    check_can_resolve(bet, ctx.accounts.arbiter.key())?;
+    require!(bet.series_length == 0, BetError::SeriesInProgress);
+    if let Some(outcome) = outcome {
+        require!(outcome < bet.outcome_count, BetError::InvalidOutcome);
+    }
+
+    apply_result(&mut ctx.accounts.bet, ctx.accounts.tournament.as_mut(), outcome)?;
+
+    // check_can_resolve garante que ainda estamos antes de resolve_deadline
+    if let Some(profile) = ctx.accounts.bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+        profile.record_resolution(true)?;
+    }
+
+    Ok(())
+}
+
+/// Validações comuns a toda resolução feita pelo árbitro.
+pub fn check_can_resolve(bet: &Bet, arbiter: Pubkey) -> Result<()> {
+    require!(arbiter == bet.arbiter, BetError::InvalidArbiter);
+    require!(!bet.has_committee(), BetError::CommitteeResolution);
+    require!(!bet.has_oracle(), BetError::OracleResolution);
+    require!(!bet.has_attestor(), BetError::AttestorResolution);
+    check_resolution_window(bet)
+}
+
+/// Bet aberto, dentro da janela de resolução e com os dois lados fundados.
+pub fn check_resolution_window(bet: &Bet) -> Result<()> {
+    let clock = Clock::get()?;
+
     require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
     require!(
         clock.unix_timestamp >= bet.resolve_ts,
         BetError::TooEarlyToResolve
     );
+    require!(
+        clock.unix_timestamp < bet.resolve_deadline,
+        BetError::DeadlinePassed
+    );
     require!(
         bet.user_a_deposited && bet.user_b_deposited,
         BetError::ParticipantsNotDeposited
     );
+    require!(!bet.is_champion_market(), BetError::InvalidTournament);
+
+    Ok(())
+}
+
+/// Registra o resultado no Bet e, em matches de torneio, avança o vencedor.
+pub fn apply_result<'info>(
+    bet: &mut Account<'info, Bet>,
+    tournament: Option<&mut Account<'info, Tournament>>,
+    outcome: Option<u8>,
+) -> Result<()> {
+    let winner_side = outcome.and_then(Bet::side_for_outcome);
+    require!(
+        !bet.in_tournament() || winner_side.is_some(),
+        BetError::TournamentMatchNeedsWinner
+    );
+
+    // Declarar vencedor (ou empate)
+    bet.winning_outcome = outcome;
+    bet.winner_side = winner_side;
+    bet.status = match outcome {
+        Some(_) => BetStatus::Resolved,
+        None => BetStatus::Draw,
+    };
 
-    // Declarar vencedor
-    bet.winner_side = Some(winner_side);
-    bet.status = BetStatus::Resolved;
+    // Com janela de disputa, os pagamentos só liberam quando ela fecha
+    if bet.dispute_window > 0 {
+        bet.dispute_ends_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(bet.dispute_window)
+            .ok_or(BetError::ArithmeticOverflow)?;
+    }
 
     emit!(WinnerDeclared {
-        bet: ctx.accounts.bet.key(),
+        bet: bet.key(),
         winner_side,
+        winning_outcome: outcome,
     });
 
+    // Match de torneio: o vencedor avança para o próximo match do bracket
+    if let (true, Some(winner_side)) = (bet.in_tournament(), winner_side) {
+        let tournament = tournament.ok_or(BetError::InvalidTournament)?;
+        require!(
+            tournament.key() == bet.tournament,
+            BetError::InvalidTournament
+        );
+
+        let winner = match winner_side {
+            Side::A => bet.user_a,
+            Side::B => bet.user_b,
+        };
+        let index = bet.match_index as usize;
+        tournament.advancing[index] = winner;
+
+        let next_match = tournament.next_match(index);
+        if next_match.is_none() {
+            tournament.champion = Some(winner);
+        }
+
+        emit!(MatchAdvanced {
+            tournament: tournament.key(),
+            match_index: bet.match_index,
+            winner,
+            next_match: next_match.map(|i| i as u8),
+        });
+    }
+
     Ok(())
 }
 
 #[event]
 pub struct WinnerDeclared {
     pub bet: Pubkey,
-    pub winner_side: Side,
+    pub winner_side: Option<Side>,
+    pub winning_outcome: Option<u8>,
+}
+
+#[event]
+pub struct MatchAdvanced {
+    pub tournament: Pubkey,
+    pub match_index: u8,
+    pub winner: Pubkey,
+    pub next_match: Option<u8>, // None = final; o vencedor é o campeão
 }
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{Bet, BetStatus};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus, ProtocolConfig, Side};
 use crate::errors::BetError;
+use crate::vault::deposit_to_bet;
 
 #[derive(Accounts)]
 pub struct DepositParticipant<'info> {
@@ -12,14 +13,27 @@ pub struct DepositParticipant<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
     pub bet: Account<'info, Bet>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub participant_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -27,9 +41,13 @@ pub fn handler(ctx: Context<DepositParticipant>) -> Result<()> {
     let clock = Clock::get()?;
 
     // Validate and mark deposit
-    let stake_lamports;
+    let stake;
     let bet_key;
+    let side;
+    let member_index;
     {
+        require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+
         let bet = &mut ctx.accounts.bet;
 
         require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
@@ -39,42 +57,82 @@ pub fn handler(ctx: Context<DepositParticipant>) -> Result<()> {
         );
 
         let participant_key = ctx.accounts.participant.key();
-        let is_user_a = participant_key == bet.user_a;
-        let is_user_b = participant_key == bet.user_b;
 
-        require!(
-            is_user_a || is_user_b,
-            BetError::InvalidParticipant
-        );
+        if bet.has_teams() {
+            // Duelo em equipe: cada membro do roster deposita o próprio stake
+            let (member_side, index) = bet
+                .find_member(participant_key)
+                .ok_or(BetError::InvalidParticipant)?;
+            let member = &mut bet.team_mut(member_side)[index];
+            require!(!member.deposited, BetError::AlreadyDeposited);
+            member.deposited = true;
 
-        if is_user_a {
-            require!(!bet.user_a_deposited, BetError::AlreadyDeposited);
-            bet.user_a_deposited = true;
+            side = member_side;
+            member_index = Some(index);
+            stake = member.stake;
         } else {
-            require!(!bet.user_b_deposited, BetError::AlreadyDeposited);
-            bet.user_b_deposited = true;
+            let is_user_a = participant_key == bet.user_a;
+            let is_user_b = participant_key == bet.user_b;
+
+            require!(
+                is_user_a || is_user_b,
+                BetError::InvalidParticipant
+            );
+
+            if is_user_a {
+                require!(!bet.user_a_deposited, BetError::AlreadyDeposited);
+                bet.user_a_deposited = true;
+                side = Side::A;
+            } else {
+                require!(!bet.user_b_deposited, BetError::AlreadyDeposited);
+                bet.user_b_deposited = true;
+                side = Side::B;
+            }
+
+            member_index = None;
+            stake = if is_user_a { bet.stake_a } else { bet.stake_b };
         }
 
-        stake_lamports = bet.stake_lamports;
         bet_key = bet.key();
     }
 
-    // Transfer stake to bet PDA
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.participant.to_account_info(),
-                to: ctx.accounts.bet.to_account_info(),
-            },
-        ),
-        stake_lamports,
+    // Transfer stake to bet PDA (ou vault, em bets de token)
+    let received = deposit_to_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.participant,
+        ctx.accounts.participant_token.as_ref(),
+        stake,
     )?;
 
+    // Creditar o que de fato entrou no vault
+    let bet = &mut ctx.accounts.bet;
+    let principal = match side {
+        Side::A => &mut bet.principal_a,
+        Side::B => &mut bet.principal_b,
+    };
+    *principal = principal
+        .checked_add(received)
+        .ok_or(BetError::ArithmeticOverflow)?;
+
+    // O lado só conta como depositado quando o time inteiro depositou
+    if let Some(index) = member_index {
+        bet.team_mut(side)[index].principal = received;
+        if bet.team(side).iter().all(|m| m.deposited) {
+            match side {
+                Side::A => bet.user_a_deposited = true,
+                Side::B => bet.user_b_deposited = true,
+            }
+        }
+    }
+
     emit!(ParticipantDeposited {
         bet: bet_key,
         participant: ctx.accounts.participant.key(),
-        amount: stake_lamports,
+        amount: received,
     });
 
     Ok(())
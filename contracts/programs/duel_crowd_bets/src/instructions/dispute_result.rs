@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus, DisputeStatus, ProtocolConfig, SupportPosition};
+use crate::errors::BetError;
+use crate::vault::deposit_to_bet;
+
+#[derive(Accounts)]
+pub struct DisputeResult<'info> {
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Obrigatório quando quem disputa é apostador e não duelista
+    pub support_position: Option<Account<'info, SupportPosition>>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub disputer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DisputeResult>) -> Result<()> {
+    let clock = Clock::get()?;
+    let disputer_key = ctx.accounts.disputer.key();
+
+    // Validações
+    let bond;
+    {
+        require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+
+        let bet = &ctx.accounts.bet;
+
+        require!(
+            matches!(bet.status, BetStatus::Resolved | BetStatus::Draw),
+            BetError::BetNotResolved
+        );
+        require!(bet.dispute_window > 0, BetError::InvalidDisputeTerms);
+        require!(
+            bet.dispute_status == DisputeStatus::None,
+            BetError::DisputeAlreadyOpen
+        );
+        require!(
+            clock.unix_timestamp < bet.dispute_ends_at,
+            BetError::DisputeWindowClosed
+        );
+
+        let is_duelist = disputer_key == bet.user_a
+            || disputer_key == bet.user_b
+            || bet.find_member(disputer_key).is_some();
+        let is_bettor = ctx.accounts.support_position
+            .as_ref()
+            .map(|position| position.bet == bet.key() && position.bettor == disputer_key)
+            .unwrap_or(false);
+        require!(is_duelist || is_bettor, BetError::NotEligibleToDispute);
+
+        bond = bet.dispute_bond;
+    }
+
+    // Bond vai para o Bet (ou vault), junto do resto do valor em jogo
+    let received = deposit_to_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.disputer,
+        ctx.accounts.disputer_token.as_ref(),
+        bond,
+    )?;
+
+    let bet = &mut ctx.accounts.bet;
+    bet.disputer = disputer_key;
+    bet.dispute_bond_posted = received;
+    bet.dispute_status = DisputeStatus::Open;
+
+    emit!(ResultDisputed {
+        bet: bet.key(),
+        disputer: disputer_key,
+        bond: received,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResultDisputed {
+    pub bet: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+}
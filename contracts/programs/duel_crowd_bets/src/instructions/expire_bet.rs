@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{ArbiterProfile, Bet, BetStatus};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct ExpireBet<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+}
+
+pub fn handler(ctx: Context<ExpireBet>) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações: o árbitro não resolveu dentro do prazo
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp >= bet.resolve_deadline,
+        BetError::ResolveDeadlineNotReached
+    );
+
+    bet.status = BetStatus::Expired;
+
+    // Em modo de reembolso a torcida recebe o valor bruto de volta,
+    // então o spread acumulado deixa de existir
+    bet.spread_pool_creators = 0;
+    bet.spread_pool_arbiter = 0;
+    bet.spread_pool_protocol = 0;
+
+    // Só conta contra o árbitro se o duelo foi fundado: sem isso não havia o que resolver
+    if bet.user_a_deposited && bet.user_b_deposited {
+        if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+            profile.record_resolution(false)?;
+        }
+    }
+
+    emit!(BetExpired {
+        bet: ctx.accounts.bet.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct BetExpired {
+    pub bet: Pubkey,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, DisputeStatus};
+use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+use crate::instructions::resolve_dispute::DisputeResolved;
+
+#[derive(Accounts)]
+pub struct ExpireDispute<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Validated in handler against `bet.disputer`
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub disputer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Permissionless: se o fallback não decidir dentro de mais uma janela de
+/// disputa, o resultado original fica valendo e o bond volta para quem disputou.
+pub fn handler(ctx: Context<ExpireDispute>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let refund;
+    {
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            ctx.accounts.disputer.key() == bet.disputer,
+            BetError::InvalidRecipient
+        );
+        require!(
+            bet.dispute_status == DisputeStatus::Open,
+            BetError::NoOpenDispute
+        );
+        let fallback_deadline = bet.fallback_deadline().ok_or(BetError::ArithmeticOverflow)?;
+        require!(
+            clock.unix_timestamp >= fallback_deadline,
+            BetError::ResolveDeadlineNotReached
+        );
+
+        bet.dispute_status = DisputeStatus::Lapsed;
+        refund = bet.dispute_bond_posted;
+    }
+
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.disputer.to_account_info(),
+        ctx.accounts.disputer_token.as_ref(),
+        refund,
+    )?;
+
+    emit!(DisputeResolved {
+        bet: ctx.accounts.bet.key(),
+        disputer: ctx.accounts.bet.disputer,
+        overturned: false,
+        bond_returned: refund,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{ProtocolConfig, Tournament};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct FundPrizePool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"tournament",
+            tournament.organizer.as_ref(),
+            tournament.tournament_id.to_le_bytes().as_ref(),
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Qualquer um (organizador, patrocinadores) pode aumentar o prêmio do campeão.
+pub fn handler(ctx: Context<FundPrizePool>, amount: u64) -> Result<()> {
+    // Validações
+    require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+    require!(amount > 0, BetError::AmountTooSmall);
+    require!(!ctx.accounts.tournament.prize_claimed, BetError::PrizeAlreadyClaimed);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.tournament.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.prize_pool = tournament.prize_pool
+        .checked_add(amount)
+        .ok_or(BetError::ArithmeticOverflow)?;
+
+    emit!(PrizePoolFunded {
+        tournament: tournament.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PrizePoolFunded {
+    pub tournament: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::program::DuelCrowdBets;
+use crate::state::ProtocolConfig;
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // Só a upgrade authority do programa pode criar a config,
+    // evitando que alguém se antecipe e vire admin
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, DuelCrowdBets>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ BetError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConfig>,
+    treasury: Pubkey,
+    min_spread_bps: u16,
+    max_spread_bps: u16,
+    min_protocol_share_bps: u16,
+) -> Result<()> {
+    // Validações
+    require!(
+        ProtocolConfig::validate_limits(min_spread_bps, max_spread_bps, min_protocol_share_bps),
+        BetError::InvalidFeeConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.treasury = treasury;
+    config.min_spread_bps = min_spread_bps;
+    config.max_spread_bps = max_spread_bps;
+    config.min_protocol_share_bps = min_protocol_share_bps;
+    config.paused = false;
+    config.bump = ctx.bumps.config;
+
+    emit!(ConfigUpdated {
+        admin: config.admin,
+        treasury,
+        min_spread_bps,
+        max_spread_bps,
+        min_protocol_share_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub min_spread_bps: u16,
+    pub max_spread_bps: u16,
+    pub min_protocol_share_bps: u16,
+}
@@ -1,15 +1,89 @@
+// Cada módulo exporta o próprio `handler`; lib.rs chama pelo caminho completo
+// (`instructions::x::handler`) e o glob serve só aos structs de Accounts e eventos.
+#![allow(ambiguous_glob_reexports)]
+
 pub mod create_bet;
+pub mod set_teams;
 pub mod deposit_participant;
+pub mod accept_challenge;
 pub mod support_bet;
 pub mod declare_winner;
+pub mod set_series;
+pub mod record_game;
+pub mod set_committee;
+pub mod vote_winner;
+pub mod set_oracle;
+pub mod resolve_with_oracle;
+pub mod set_attestor;
+pub mod resolve_with_attestation;
+pub mod set_dispute_terms;
+pub mod dispute_result;
+pub mod resolve_dispute;
+pub mod expire_dispute;
+pub mod set_arbiter_bond;
+pub mod post_arbiter_bond;
+pub mod release_arbiter_bond;
+pub mod slash_arbiter_bond;
 pub mod withdraw_principal;
 pub mod claim_support;
 pub mod withdraw_spread;
+pub mod cancel_bet;
+pub mod refund_participant;
+pub mod refund_support;
+pub mod expire_bet;
+pub mod mutual_cancel;
+pub mod reassign_arbiter;
+pub mod close_support_position;
+pub mod close_bet;
+pub mod initialize_config;
+pub mod update_config;
+pub mod set_paused;
+pub mod register_arbiter;
+pub mod create_tournament;
+pub mod fund_prize_pool;
+pub mod register_match;
+pub mod claim_tournament_prize;
+pub mod resolve_champion_market;
 
 pub use create_bet::*;
+pub use set_teams::*;
 pub use deposit_participant::*;
+pub use accept_challenge::*;
 pub use support_bet::*;
 pub use declare_winner::*;
+pub use set_series::*;
+pub use record_game::*;
+pub use set_committee::*;
+pub use vote_winner::*;
+pub use set_oracle::*;
+pub use resolve_with_oracle::*;
+pub use set_attestor::*;
+pub use resolve_with_attestation::*;
+pub use set_dispute_terms::*;
+pub use dispute_result::*;
+pub use resolve_dispute::*;
+pub use expire_dispute::*;
+pub use set_arbiter_bond::*;
+pub use post_arbiter_bond::*;
+pub use release_arbiter_bond::*;
+pub use slash_arbiter_bond::*;
 pub use withdraw_principal::*;
 pub use claim_support::*;
 pub use withdraw_spread::*;
+pub use cancel_bet::*;
+pub use refund_participant::*;
+pub use refund_support::*;
+pub use expire_bet::*;
+pub use mutual_cancel::*;
+pub use reassign_arbiter::*;
+pub use close_support_position::*;
+pub use close_bet::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use set_paused::*;
+pub use register_arbiter::*;
+pub use create_tournament::*;
+pub use fund_prize_pool::*;
+pub use register_match::*;
+pub use claim_tournament_prize::*;
+pub use resolve_champion_market::*;
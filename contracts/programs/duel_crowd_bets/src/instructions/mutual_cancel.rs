@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+use crate::instructions::cancel_bet::BetCancelled;
+
+#[derive(Accounts)]
+pub struct MutualCancel<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Regra de reembolso: duelistas recuperam o stake via `refund_participant`
+/// e cada apostador recupera o valor bruto (fee incluído) via `refund_support`.
+/// Como o fee volta para a torcida, os pools de spread são zerados e
+/// `withdraw_spread` não paga nada.
+pub fn handler(ctx: Context<MutualCancel>) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.arbiter.key() == bet.arbiter,
+        BetError::InvalidArbiter
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp < bet.deadline_crowd,
+        BetError::DeadlinePassed
+    );
+
+    bet.status = BetStatus::Cancelled;
+    bet.spread_pool_creators = 0;
+    bet.spread_pool_arbiter = 0;
+    bet.spread_pool_protocol = 0;
+
+    emit!(BetCancelled {
+        bet: ctx.accounts.bet.key(),
+    });
+
+    Ok(())
+}
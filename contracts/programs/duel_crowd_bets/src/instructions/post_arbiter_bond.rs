@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{ArbiterBond, Bet, BetStatus, ProtocolConfig};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct PostArbiterBond<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = arbiter,
+        space = ArbiterBond::LEN,
+        seeds = [b"arbiter_bond", bet.key().as_ref()],
+        bump
+    )]
+    pub arbiter_bond: Account<'info, ArbiterBond>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PostArbiterBond>) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+    require!(
+        ctx.accounts.arbiter.key() == bet.arbiter,
+        BetError::InvalidArbiter
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(bet.arbiter_bond > 0, BetError::ArbiterBondNotPosted);
+    require!(!bet.arbiter_bond_posted, BetError::ArbiterBondAlreadyPosted);
+
+    let amount = bet.arbiter_bond;
+    bet.arbiter_bond_posted = true;
+
+    // Transfer do bond para o escrow (o rent do escrow é pago à parte pelo init)
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.arbiter.to_account_info(),
+                to: ctx.accounts.arbiter_bond.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let arbiter_bond = &mut ctx.accounts.arbiter_bond;
+    arbiter_bond.bet = ctx.accounts.bet.key();
+    arbiter_bond.arbiter = ctx.accounts.arbiter.key();
+    arbiter_bond.amount = amount;
+    arbiter_bond.bump = ctx.bumps.arbiter_bond;
+
+    emit!(ArbiterBondPosted {
+        bet: ctx.accounts.bet.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterBondPosted {
+    pub bet: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use crate::state::{ArbiterBond, ArbiterProfile, Bet, BetStatus};
+use crate::errors::BetError;
+use crate::instructions::release_arbiter_bond::ArbiterBondReleased;
+use crate::instructions::slash_arbiter_bond::ArbiterBondSlashed;
+
+#[derive(Accounts)]
+#[instruction(new_arbiter: Pubkey)]
+pub struct ReassignArbiter<'info> {
+    #[account(mut)]
+    pub user_a: Signer<'info>,
+
+    #[account(mut)]
+    pub user_b: Signer<'info>,
+
+    /// CHECK: Validated in handler against `bet.arbiter`. Sem a assinatura dele,
+    /// troca unilateral dos duelistas, só depois do timeout do árbitro
+    #[account(mut)]
+    pub arbiter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Obrigatório quando o árbitro atual tem bond postado: o escrow é liquidado aqui
+    #[account(
+        mut,
+        seeds = [b"arbiter_bond", bet.key().as_ref()],
+        bump = arbiter_bond.bump
+    )]
+    pub arbiter_bond: Option<Account<'info, ArbiterBond>>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro atual
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+
+    // Perfil do novo árbitro: com a assinatura dele, o bet passa a atualizar o histórico
+    #[account(
+        seeds = [b"arbiter_profile", new_arbiter.as_ref()],
+        bump = new_arbiter_profile.bump
+    )]
+    pub new_arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+
+    #[account(address = new_arbiter)]
+    pub new_arbiter_signer: Option<Signer<'info>>,
+}
+
+/// Troca o árbitro de um bet ainda aberto. Com a assinatura do árbitro atual
+/// a troca é imediata; sem ela, os duelistas só podem trocá-lo depois de
+/// `arbiter_timeout`, e a troca conta como prazo perdido no perfil dele.
+/// Um bond já postado é liquidado na troca, como em `slash_arbiter_bond` e
+/// `release_arbiter_bond`: confiscado para os duelistas se o árbitro foi trocado
+/// por omissão com o duelo fundado, devolvido a ele nos outros casos. O novo
+/// árbitro posta o próprio bond.
+pub fn handler(ctx: Context<ReassignArbiter>, new_arbiter: Pubkey) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp < bet.resolve_deadline,
+        BetError::DeadlinePassed
+    );
+    // Torneios exigem o árbitro do bracket; comitê, oráculo e atestador não usam `arbiter`
+    require!(
+        bet.resolved_by_arbiter() && !bet.in_tournament(),
+        BetError::CannotReassignArbiter
+    );
+    require!(
+        ctx.accounts.arbiter.key() == bet.arbiter,
+        BetError::InvalidArbiter
+    );
+    require!(
+        new_arbiter != Pubkey::default()
+            && new_arbiter != bet.arbiter
+            && new_arbiter != bet.user_a
+            && new_arbiter != bet.user_b
+            && new_arbiter != bet.fallback_arbiter,
+        BetError::InvalidArbiter
+    );
+
+    let timed_out = !ctx.accounts.arbiter.is_signer;
+    if timed_out {
+        require!(
+            clock.unix_timestamp >= bet.arbiter_timeout(),
+            BetError::ArbiterTimeoutNotReached
+        );
+    }
+
+    // Árbitro trocado por omissão com o duelo fundado: prazo perdido
+    let missed_deadline = timed_out && bet.user_a_deposited && bet.user_b_deposited;
+    if missed_deadline {
+        if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+            profile.record_resolution(false)?;
+        }
+    }
+
+    if bet.arbiter_bond_posted && !bet.arbiter_bond_settled {
+        let arbiter_bond = ctx.accounts.arbiter_bond
+            .as_ref()
+            .ok_or(BetError::CannotReassignArbiter)?;
+        let bond = arbiter_bond.amount;
+
+        if missed_deadline {
+            let half = bond / 2;
+            let (amount_a, amount_b) = (half, bond - half);
+
+            let escrow = arbiter_bond.to_account_info();
+            **escrow.try_borrow_mut_lamports()? -= bond;
+            **ctx.accounts.user_a.to_account_info().try_borrow_mut_lamports()? += amount_a;
+            **ctx.accounts.user_b.to_account_info().try_borrow_mut_lamports()? += amount_b;
+
+            emit!(ArbiterBondSlashed {
+                bet: bet.key(),
+                arbiter: bet.arbiter,
+                amount_a,
+                amount_b,
+            });
+        } else {
+            emit!(ArbiterBondReleased {
+                bet: bet.key(),
+                arbiter: bet.arbiter,
+                bond,
+                fee: 0,
+            });
+        }
+
+        // Rent do escrow (e o bond, se não confiscado) volta ao árbitro
+        arbiter_bond.close(ctx.accounts.arbiter.to_account_info())?;
+        bet.arbiter_bond_posted = false;
+    }
+
+    let previous_arbiter = bet.arbiter;
+    bet.arbiter = new_arbiter;
+    bet.arbiter_profile = match (
+        &ctx.accounts.new_arbiter_profile,
+        &ctx.accounts.new_arbiter_signer,
+    ) {
+        (Some(profile), Some(_)) => profile.key(),
+        _ => Pubkey::default(),
+    };
+
+    emit!(ArbiterReassigned {
+        bet: bet.key(),
+        previous_arbiter,
+        new_arbiter,
+        timed_out,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterReassigned {
+    pub bet: Pubkey,
+    pub previous_arbiter: Pubkey,
+    pub new_arbiter: Pubkey,
+    pub timed_out: bool,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{ArbiterProfile, Bet, Side, Tournament};
+use crate::errors::BetError;
+use crate::instructions::declare_winner::{apply_result, check_can_resolve};
+
+#[derive(Accounts)]
+pub struct RecordGame<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Obrigatório quando o Bet é um match de torneio
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+}
+
+pub fn handler(ctx: Context<RecordGame>, winner_side: Side) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    check_can_resolve(bet, ctx.accounts.arbiter.key())?;
+    require!(bet.series_length > 0, BetError::NotSeries);
+
+    let wins = match winner_side {
+        Side::A => &mut bet.wins_a,
+        Side::B => &mut bet.wins_b,
+    };
+    *wins = wins.checked_add(1).ok_or(BetError::ArithmeticOverflow)?;
+    let side_wins = *wins;
+
+    emit!(GameRecorded {
+        bet: bet.key(),
+        game_number: bet.wins_a + bet.wins_b,
+        winner_side,
+        wins_a: bet.wins_a,
+        wins_b: bet.wins_b,
+    });
+
+    // Série decidida: resolve como se o árbitro tivesse declarado o vencedor
+    if side_wins >= bet.series_wins_needed() {
+        let outcome = match winner_side {
+            Side::A => 0,
+            Side::B => 1,
+        };
+        apply_result(bet, ctx.accounts.tournament.as_mut(), Some(outcome))?;
+
+        if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+            profile.record_resolution(true)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct GameRecorded {
+    pub bet: Pubkey,
+    pub game_number: u8,
+    pub winner_side: Side,
+    pub wins_a: u8,
+    pub wins_b: u8,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+
+#[derive(Accounts)]
+pub struct RefundParticipant<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub participant_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<RefundParticipant>) -> Result<()> {
+    let amount;
+    {
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            bet.status == BetStatus::Cancelled,
+            BetError::BetNotCancelled
+        );
+
+        amount = bet.take_stake_refund(ctx.accounts.participant.key())?;
+    }
+
+    // Devolver o stake do PDA para o participante
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.participant.to_account_info(),
+        ctx.accounts.participant_token.as_ref(),
+        amount,
+    )?;
+
+    emit!(ParticipantRefunded {
+        bet: ctx.accounts.bet.key(),
+        participant: ctx.accounts.participant.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ParticipantRefunded {
+    pub bet: Pubkey,
+    pub participant: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus, SupportPosition};
+use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+
+#[derive(Accounts)]
+pub struct RefundSupport<'info> {
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"support",
+            bet.key().as_ref(),
+            bettor.key().as_ref(),
+            &[support_position.outcome],
+        ],
+        bump = support_position.bump
+    )]
+    pub support_position: Account<'info, SupportPosition>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bettor_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<RefundSupport>) -> Result<()> {
+    let bet = &ctx.accounts.bet;
+    let support_position = &mut ctx.accounts.support_position;
+
+    // Validações
+    require!(
+        bet.status == BetStatus::Cancelled,
+        BetError::BetNotCancelled
+    );
+    require!(!support_position.claimed, BetError::AlreadyClaimed);
+    require!(
+        support_position.bet == ctx.accounts.bet.key(),
+        BetError::InvalidSupportPosition
+    );
+    require!(
+        support_position.bettor == ctx.accounts.bettor.key(),
+        BetError::InvalidSupportPosition
+    );
+
+    ctx.accounts.bet.open_positions = ctx.accounts.bet.open_positions
+        .checked_sub(1)
+        .ok_or(BetError::ArithmeticOverflow)?;
+
+    // Reembolso integral: valor bruto, incluindo o fee de spread
+    let amount = support_position.gross_amount;
+
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.bettor.to_account_info(),
+        ctx.accounts.bettor_token.as_ref(),
+        amount,
+    )?;
+
+    support_position.claimed = true;
+
+    emit!(SupportRefunded {
+        bet: ctx.accounts.bet.key(),
+        bettor: ctx.accounts.bettor.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SupportRefunded {
+    pub bet: Pubkey,
+    pub bettor: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::ArbiterProfile;
+
+#[derive(Accounts)]
+pub struct RegisterArbiter<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        init,
+        payer = arbiter,
+        space = ArbiterProfile::LEN,
+        seeds = [b"arbiter_profile", arbiter.key().as_ref()],
+        bump
+    )]
+    pub arbiter_profile: Account<'info, ArbiterProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cria o perfil público do árbitro, começando sem histórico. Só bets em que o
+/// árbitro assina `create_bet` (ou `reassign_arbiter`, como novo árbitro) junto
+/// com o perfil passam a atualizá-lo.
+pub fn handler(ctx: Context<RegisterArbiter>) -> Result<()> {
+    let profile = &mut ctx.accounts.arbiter_profile;
+    profile.arbiter = ctx.accounts.arbiter.key();
+    profile.resolved_count = 0;
+    profile.on_time_count = 0;
+    profile.overturned_count = 0;
+    profile.fees_earned = 0;
+    profile.bump = ctx.bumps.arbiter_profile;
+
+    emit!(ArbiterRegistered {
+        arbiter: profile.arbiter,
+        profile: profile.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterRegistered {
+    pub arbiter: Pubkey,
+    pub profile: Pubkey,
+}
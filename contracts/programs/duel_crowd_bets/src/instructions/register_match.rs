@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, Tournament};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct RegisterMatch<'info> {
+    pub organizer: Signer<'info>,
+
+    // Quem criou o Bet também precisa consentir em ligá-lo ao torneio
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"tournament",
+            tournament.organizer.as_ref(),
+            tournament.tournament_id.to_le_bytes().as_ref(),
+        ],
+        bump = tournament.bump,
+        has_one = organizer @ BetError::Unauthorized
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Bet que ocupa a posição hoje, só para substituí-lo; validado em `check_slot_free`
+    pub previous_bet: Option<UncheckedAccount<'info>>,
+}
+
+/// A posição está livre se nunca foi registrada, ou se o Bet registrado morreu
+/// sem resultado (cancelado, expirado ou já fechado depois disso), para o
+/// bracket não travar num match que nunca aconteceu.
+fn check_slot_free(slot: Pubkey, previous_bet: Option<&UncheckedAccount>) -> Result<()> {
+    if slot == Pubkey::default() {
+        return Ok(());
+    }
+
+    let previous_bet = previous_bet.ok_or(BetError::MatchAlreadyRegistered)?;
+    require!(
+        previous_bet.key() == slot,
+        BetError::MatchAlreadyRegistered
+    );
+
+    // Fechado por close_bet: só acontece depois de liquidado
+    if previous_bet.data_is_empty() {
+        return Ok(());
+    }
+    require!(
+        previous_bet.owner == &crate::ID,
+        BetError::MatchAlreadyRegistered
+    );
+    let data = previous_bet.try_borrow_data()?;
+    let previous = Bet::try_deserialize(&mut &data[..])?;
+    require!(
+        matches!(previous.status, BetStatus::Cancelled | BetStatus::Expired),
+        BetError::MatchAlreadyRegistered
+    );
+
+    Ok(())
+}
+
+/// Liga um Bet já criado a uma posição do bracket. Os duelistas do Bet precisam
+/// ser exatamente os jogadores daquele match; com `Tournament::CHAMPION_MARKET`
+/// o Bet vira o mercado da torcida no campeão (um outcome por jogador, na
+/// ordem de `players`), resolvido por `resolve_champion_market`. O Bet ainda
+/// não pode ter dinheiro de ninguém: nem stakes nem torcida. Um match (ou o
+/// mercado) cujo Bet foi cancelado ou expirou pode ser registrado de novo,
+/// passando o Bet antigo em `previous_bet`.
+pub fn handler(ctx: Context<RegisterMatch>, match_index: u8) -> Result<()> {
+    let tournament = &mut ctx.accounts.tournament;
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.payer.key() == bet.payer,
+        BetError::InvalidPayer
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited && bet.open_positions == 0,
+        BetError::AlreadyDeposited
+    );
+    require!(!bet.in_tournament(), BetError::MatchAlreadyRegistered);
+    // O bracket avança na resolução; um resultado revertido depois não teria como voltar
+    require!(bet.dispute_window == 0, BetError::InvalidTournament);
+
+    let previous_bet = ctx.accounts.previous_bet.as_ref();
+
+    if match_index == Tournament::CHAMPION_MARKET {
+        check_slot_free(tournament.champion_market, previous_bet)?;
+        require!(tournament.champion.is_none(), BetError::InvalidTournament);
+        require!(
+            bet.outcome_count as usize == tournament.players.len(),
+            BetError::InvalidOutcome
+        );
+
+        tournament.champion_market = bet.key();
+    } else {
+        let index = match_index as usize;
+        require!(index < tournament.matches.len(), BetError::InvalidBracket);
+        // Match já decidido não volta, mesmo que o Bet tenha sido fechado
+        require!(
+            tournament.advancing[index] == Pubkey::default(),
+            BetError::MatchAlreadyRegistered
+        );
+        check_slot_free(tournament.matches[index], previous_bet)?;
+
+        let (player_a, player_b) = tournament
+            .match_players(index)
+            .ok_or(BetError::MatchNotReady)?;
+        require!(
+            bet.user_a == player_a && bet.user_b == player_b,
+            BetError::InvalidParticipant
+        );
+        require!(bet.arbiter == tournament.arbiter, BetError::InvalidArbiter);
+
+        tournament.matches[index] = bet.key();
+    }
+
+    bet.tournament = tournament.key();
+    bet.match_index = match_index;
+
+    emit!(MatchRegistered {
+        tournament: tournament.key(),
+        bet: bet.key(),
+        match_index,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MatchRegistered {
+    pub tournament: Pubkey,
+    pub bet: Pubkey,
+    pub match_index: u8,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{ArbiterBond, ArbiterProfile, Bet, BetStatus, DisputeStatus, SpreadRecipient};
+use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+
+#[derive(Accounts)]
+pub struct ReleaseArbiterBond<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Fechar o escrow devolve bond e rent ao árbitro
+    #[account(
+        mut,
+        close = arbiter,
+        seeds = [b"arbiter_bond", bet.key().as_ref()],
+        bump = arbiter_bond.bump
+    )]
+    pub arbiter_bond: Account<'info, ArbiterBond>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub arbiter_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+}
+
+/// Devolve o bond com o fee do árbitro após resolução não contestada ou mantida.
+/// Bets cancelados, ou expirados sem o duelo ter sido fundado, também liberam
+/// o bond: o árbitro não tinha o que resolver.
+pub fn handler(ctx: Context<ReleaseArbiterBond>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let fee;
+    {
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            ctx.accounts.arbiter.key() == bet.arbiter,
+            BetError::InvalidArbiter
+        );
+        require!(!bet.arbiter_bond_settled, BetError::CannotReleaseBond);
+
+        let funded = bet.user_a_deposited && bet.user_b_deposited;
+        fee = match bet.status {
+            BetStatus::Resolved | BetStatus::Draw => {
+                require!(
+                    bet.is_result_final(clock.unix_timestamp)
+                        && bet.dispute_status != DisputeStatus::Overturned,
+                    BetError::CannotReleaseBond
+                );
+                bet.spread_share_amount(SpreadRecipient::Arbiter)
+            }
+            BetStatus::Cancelled => 0,
+            BetStatus::Expired if !funded => 0,
+            _ => return err!(BetError::CannotReleaseBond),
+        };
+
+        bet.arbiter_bond_settled = true;
+        bet.settlement_claims |= Bet::spread_claim_flag(SpreadRecipient::Arbiter);
+
+        if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+            profile.record_fee(bet, fee)?;
+        }
+    }
+
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.arbiter.to_account_info(),
+        ctx.accounts.arbiter_token.as_ref(),
+        fee,
+    )?;
+
+    emit!(ArbiterBondReleased {
+        bet: ctx.accounts.bet.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+        bond: ctx.accounts.arbiter_bond.amount,
+        fee,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterBondReleased {
+    pub bet: Pubkey,
+    pub arbiter: Pubkey,
+    pub bond: u64,
+    pub fee: u64,
+}
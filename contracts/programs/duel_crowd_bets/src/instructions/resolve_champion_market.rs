@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, Tournament};
+use crate::errors::BetError;
+use crate::instructions::declare_winner::WinnerDeclared;
+
+#[derive(Accounts)]
+pub struct ResolveChampionMarket<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"tournament",
+            tournament.organizer.as_ref(),
+            tournament.tournament_id.to_le_bytes().as_ref(),
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Permissionless: assim que a final define o campeão, qualquer um resolve o
+/// mercado da torcida no campeão, fechando novas apostas. Não há vencedor no
+/// duelo desse Bet, então eventuais stakes dos duelistas são devolvidos.
+pub fn handler(ctx: Context<ResolveChampionMarket>) -> Result<()> {
+    let tournament = &ctx.accounts.tournament;
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(
+        bet.is_champion_market() && bet.tournament == tournament.key(),
+        BetError::InvalidTournament
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp < bet.resolve_deadline,
+        BetError::DeadlinePassed
+    );
+
+    let champion = tournament.champion.ok_or(BetError::TournamentNotFinished)?;
+    let outcome = tournament.players
+        .iter()
+        .position(|player| *player == champion)
+        .ok_or(BetError::InvalidTournament)? as u8;
+
+    bet.winning_outcome = Some(outcome);
+    bet.winner_side = None;
+    bet.status = BetStatus::Resolved;
+
+    emit!(WinnerDeclared {
+        bet: bet.key(),
+        winner_side: None,
+        winning_outcome: Some(outcome),
+    });
+
+    Ok(())
+}
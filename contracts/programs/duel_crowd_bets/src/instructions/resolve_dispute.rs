@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{ArbiterProfile, Bet, BetStatus, DisputeStatus};
+use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+use crate::instructions::declare_winner::WinnerDeclared;
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    pub fallback_arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Validated in handler against `bet.disputer`; receives the bond back if the result is overturned
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub disputer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
+    #[account(mut)]
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
+}
+
+/// O fallback informa o resultado correto. Se for o mesmo já declarado, o
+/// resultado é mantido e o bond vai para o pool do protocolo; se for outro,
+/// o resultado é substituído e o bond volta para quem disputou.
+pub fn handler(ctx: Context<ResolveDispute>, outcome: Option<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let overturned;
+    let refund;
+    {
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            ctx.accounts.fallback_arbiter.key() == bet.fallback_arbiter,
+            BetError::InvalidArbiter
+        );
+        require!(
+            ctx.accounts.disputer.key() == bet.disputer,
+            BetError::InvalidRecipient
+        );
+        require!(
+            bet.dispute_status == DisputeStatus::Open,
+            BetError::NoOpenDispute
+        );
+        let fallback_deadline = bet.fallback_deadline().ok_or(BetError::ArithmeticOverflow)?;
+        require!(
+            clock.unix_timestamp < fallback_deadline,
+            BetError::DeadlinePassed
+        );
+        if let Some(outcome) = outcome {
+            require!(outcome < bet.outcome_count, BetError::InvalidOutcome);
+        }
+
+        overturned = outcome != bet.winning_outcome;
+        if overturned {
+            bet.winning_outcome = outcome;
+            bet.winner_side = outcome.and_then(Bet::side_for_outcome);
+            bet.status = match outcome {
+                Some(_) => BetStatus::Resolved,
+                None => BetStatus::Draw,
+            };
+            bet.dispute_status = DisputeStatus::Overturned;
+            refund = bet.dispute_bond_posted;
+
+            // Árbitro com bond revertido perde o fee para os duelistas
+            if bet.arbiter_bond_posted {
+                bet.spread_pool_creators = bet.spread_pool_creators
+                    .checked_add(bet.spread_pool_arbiter)
+                    .ok_or(BetError::ArithmeticOverflow)?;
+                bet.spread_pool_arbiter = 0;
+            }
+
+            if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+                profile.record_overturn()?;
+            }
+        } else {
+            // Disputa improcedente: o bond é confiscado para o protocolo
+            bet.spread_pool_protocol = bet.spread_pool_protocol
+                .checked_add(bet.dispute_bond_posted)
+                .ok_or(BetError::ArithmeticOverflow)?;
+            bet.dispute_status = DisputeStatus::Upheld;
+            refund = 0;
+        }
+    }
+
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.disputer.to_account_info(),
+        ctx.accounts.disputer_token.as_ref(),
+        refund,
+    )?;
+
+    let bet = &ctx.accounts.bet;
+    if overturned {
+        emit!(WinnerDeclared {
+            bet: bet.key(),
+            winner_side: bet.winner_side,
+            winning_outcome: bet.winning_outcome,
+        });
+    }
+
+    emit!(DisputeResolved {
+        bet: bet.key(),
+        disputer: bet.disputer,
+        overturned,
+        bond_returned: refund,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub bet: Pubkey,
+    pub disputer: Pubkey,
+    pub overturned: bool,
+    pub bond_returned: u64,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use crate::state::{Bet, Side, Tournament};
+use crate::errors::BetError;
+use crate::attestation::{attestation_message, verify_ed25519_instruction};
+use crate::instructions::declare_winner::{apply_result, check_resolution_window};
+
+#[derive(Accounts)]
+pub struct ResolveWithAttestation<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 verification
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    // Obrigatório quando o Bet é um match de torneio
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+}
+
+/// Permissionless: a transação precisa trazer, logo antes desta instrução, a
+/// verificação Ed25519 da assinatura do `attestor` sobre
+/// `(bet, winner_side, timestamp)`. O timestamp atestado precisa cair entre
+/// `resolve_ts` e agora.
+pub fn handler(
+    ctx: Context<ResolveWithAttestation>,
+    winner_side: Side,
+    timestamp: i64,
+) -> Result<()> {
+    let bet = &ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(bet.has_attestor(), BetError::InvalidAttestor);
+    check_resolution_window(bet)?;
+    require!(
+        timestamp >= bet.resolve_ts && timestamp <= clock.unix_timestamp,
+        BetError::InvalidAttestation
+    );
+
+    let message = attestation_message(&bet.key(), winner_side, timestamp);
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions.to_account_info(),
+        &bet.attestor,
+        &message,
+    )?;
+
+    emit!(ResultAttested {
+        bet: bet.key(),
+        attestor: bet.attestor,
+        winner_side,
+        timestamp,
+    });
+
+    let outcome = match winner_side {
+        Side::A => 0,
+        Side::B => 1,
+    };
+    apply_result(&mut ctx.accounts.bet, ctx.accounts.tournament.as_mut(), Some(outcome))
+}
+
+#[event]
+pub struct ResultAttested {
+    pub bet: Pubkey,
+    pub attestor: Pubkey,
+    pub winner_side: Side,
+    pub timestamp: i64,
+}
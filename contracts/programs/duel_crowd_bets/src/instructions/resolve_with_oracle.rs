@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, PriceComparison, Tournament};
+use crate::errors::BetError;
+use crate::oracle::load_pyth_price;
+use crate::instructions::declare_winner::{apply_result, check_resolution_window};
+
+#[derive(Accounts)]
+pub struct ResolveWithOracle<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Pinned to `bet.oracle_feed`; owner and layout validated in `load_pyth_price`
+    #[account(address = bet.oracle_feed @ BetError::InvalidPriceFeed)]
+    pub price_feed: UncheckedAccount<'info>,
+
+    // Obrigatório quando o Bet é um match de torneio
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+}
+
+/// Permissionless: qualquer um pode resolver com um preço publicado entre
+/// `resolve_ts` e `resolve_ts + max_price_age`, com confiança dentro do limite.
+/// Preço igual ao strike conta como vitória de B.
+pub fn handler(ctx: Context<ResolveWithOracle>) -> Result<()> {
+    let bet = &ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(bet.has_oracle(), BetError::InvalidOracleTerms);
+    check_resolution_window(bet)?;
+
+    let price = load_pyth_price(&ctx.accounts.price_feed.to_account_info())?;
+    require!(price.expo == bet.strike_expo, BetError::InvalidPriceFeed);
+    require!(price.trading, BetError::StalePrice);
+    // Publicado em [resolve_ts, resolve_ts + max_price_age], e nunca no futuro
+    require!(
+        price.publish_time >= bet.resolve_ts
+            && price.publish_time <= bet.resolve_ts.saturating_add(bet.max_price_age)
+            && price.publish_time <= clock.unix_timestamp,
+        BetError::StalePrice
+    );
+    let confidence_bps = price
+        .confidence_bps()
+        .ok_or(BetError::PriceConfidenceTooWide)?;
+    require!(
+        confidence_bps <= bet.max_confidence_bps as u64,
+        BetError::PriceConfidenceTooWide
+    );
+
+    let a_wins = match bet.price_comparison {
+        PriceComparison::Above => price.price > bet.strike_price,
+        PriceComparison::Below => price.price < bet.strike_price,
+    };
+    let outcome = if a_wins { 0 } else { 1 };
+
+    emit!(OraclePriceUsed {
+        bet: bet.key(),
+        price: price.price,
+        conf: price.conf,
+        expo: price.expo,
+        publish_time: price.publish_time,
+    });
+
+    apply_result(&mut ctx.accounts.bet, ctx.accounts.tournament.as_mut(), Some(outcome))
+}
+
+#[event]
+pub struct OraclePriceUsed {
+    pub bet: Pubkey,
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetArbiterBond<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Exige que o árbitro poste `amount` lamports de bond antes de a torcida abrir.
+pub fn handler(ctx: Context<SetArbiterBond>, amount: u64) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.payer.key() == bet.payer,
+        BetError::InvalidPayer
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(!bet.arbiter_bond_posted, BetError::ArbiterBondAlreadyPosted);
+    // Com comitê não há um árbitro único a quem atribuir o bond
+    require!(!bet.has_committee(), BetError::InvalidCommittee);
+    // Bet resolvido pelo oráculo ou por atestação: o árbitro não tem resultado a garantir
+    require!(!bet.has_oracle(), BetError::OracleResolution);
+    require!(!bet.has_attestor(), BetError::AttestorResolution);
+    require!(amount > 0, BetError::AmountTooSmall);
+
+    bet.arbiter_bond = amount;
+
+    emit!(ArbiterBondSet {
+        bet: bet.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterBondSet {
+    pub bet: Pubkey,
+    pub amount: u64,
+}
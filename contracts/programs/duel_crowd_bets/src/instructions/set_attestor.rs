@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetAttestor<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Delega o resultado a uma chave off-chain (ex.: o servidor do jogo): qualquer
+/// um pode resolver com `resolve_with_attestation` apresentando a assinatura
+/// dela sobre `(bet, winner_side, timestamp)`. O árbitro deixa de resolver,
+/// mas segue recebendo sua fatia do spread. Como a chave decide sozinha, os
+/// dois duelistas precisam assinar a escolha.
+pub fn handler(ctx: Context<SetAttestor>, attestor: Pubkey) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(
+        !bet.has_attestor()
+            && !bet.has_oracle()
+            && !bet.has_committee()
+            && bet.series_length == 0
+            && bet.arbiter_bond == 0,
+        BetError::InvalidAttestor
+    );
+    // A atestação só decide entre A e B
+    require!(bet.outcome_count == 2, BetError::InvalidAttestor);
+    require!(attestor != Pubkey::default(), BetError::InvalidAttestor);
+
+    bet.attestor = attestor;
+
+    emit!(AttestorSet {
+        bet: bet.key(),
+        attestor,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestorSet {
+    pub bet: Pubkey,
+    pub attestor: Pubkey,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, CommitteeMember, MAX_COMMITTEE};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetCommittee<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Substitui o árbitro único por um comitê M-de-N: cada membro vota com
+/// `vote_winner` e o Bet resolve quando `threshold` votos concordam.
+/// O threshold precisa ser maioria, para que só um resultado possa atingi-lo.
+/// Os dois duelistas assinam: o comitê decide o dinheiro deles, e nenhum dos
+/// lados pode trocá-lo na frente do depósito do outro.
+pub fn handler(ctx: Context<SetCommittee>, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(
+        !bet.has_committee()
+            && !bet.has_oracle()
+            && !bet.has_attestor()
+            && bet.series_length == 0
+            && bet.arbiter_bond == 0,
+        BetError::InvalidCommittee
+    );
+    require!(
+        members.len() >= 2 && members.len() <= MAX_COMMITTEE,
+        BetError::InvalidCommittee
+    );
+    require!(
+        threshold as usize * 2 > members.len() && threshold as usize <= members.len(),
+        BetError::InvalidCommittee
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(!members[i + 1..].contains(member), BetError::InvalidCommittee);
+        // Quem tem stake no resultado ou julga a disputa não vota
+        require!(
+            !bet.is_duelist(*member) && *member != bet.fallback_arbiter,
+            BetError::InvalidCommittee
+        );
+    }
+
+    bet.committee = members
+        .iter()
+        .map(|&member| CommitteeMember {
+            member,
+            voted: false,
+            vote: None,
+            fee_claimed: false,
+        })
+        .collect();
+    bet.committee_threshold = threshold;
+
+    emit!(CommitteeSet {
+        bet: bet.key(),
+        members,
+        threshold,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CommitteeSet {
+    pub bet: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetDisputeTerms<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Abre uma janela de disputa após cada resolução. Durante a janela os
+/// pagamentos ficam bloqueados e um duelista ou apostador pode postar
+/// `dispute_bond` (na moeda do bet) para levar o resultado ao `fallback_arbiter`.
+/// O fallback tem a palavra final, então os dois duelistas assinam a escolha.
+pub fn handler(
+    ctx: Context<SetDisputeTerms>,
+    fallback_arbiter: Pubkey,
+    dispute_window: i64,
+    dispute_bond: u64,
+) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(!bet.in_tournament(), BetError::InvalidDisputeTerms);
+    require!(
+        dispute_window > 0 && dispute_bond > 0,
+        BetError::InvalidDisputeTerms
+    );
+    require!(
+        fallback_arbiter != Pubkey::default() && fallback_arbiter != bet.arbiter,
+        BetError::InvalidDisputeTerms
+    );
+    // Quem julga a disputa não pode ter stake nem voto no resultado
+    require!(
+        !bet.is_duelist(fallback_arbiter)
+            && bet.committee.iter().all(|c| c.member != fallback_arbiter),
+        BetError::InvalidDisputeTerms
+    );
+
+    bet.fallback_arbiter = fallback_arbiter;
+    bet.dispute_window = dispute_window;
+    bet.dispute_bond = dispute_bond;
+
+    emit!(DisputeTermsSet {
+        bet: bet.key(),
+        fallback_arbiter,
+        dispute_window,
+        dispute_bond,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DisputeTermsSet {
+    pub bet: Pubkey,
+    pub fallback_arbiter: Pubkey,
+    pub dispute_window: i64,
+    pub dispute_bond: u64,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, PriceComparison};
+use crate::errors::BetError;
+use crate::oracle::load_pyth_price;
+
+#[derive(Accounts)]
+pub struct SetOracle<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    /// CHECK: Owner and layout validated in `load_pyth_price`
+    pub price_feed: UncheckedAccount<'info>,
+}
+
+/// Transforma o duelo numa aposta de preço ("SOL acima de X em T"): depois de
+/// `resolve_ts` qualquer um chama `resolve_with_oracle`, que lê o feed Pyth e
+/// declara A ou B vencedor conforme `comparison`. O árbitro deixa de resolver,
+/// mas segue recebendo sua fatia do spread. O strike decide quem ganha, então
+/// os dois duelistas assinam os termos.
+pub fn handler(
+    ctx: Context<SetOracle>,
+    strike_price: i64,
+    strike_expo: i32,
+    comparison: PriceComparison,
+    max_price_age: i64,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let price_feed = ctx.accounts.price_feed.key();
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(
+        !bet.has_oracle()
+            && !bet.has_attestor()
+            && !bet.has_committee()
+            && bet.series_length == 0
+            && bet.arbiter_bond == 0,
+        BetError::InvalidOracleTerms
+    );
+    // O oráculo só decide entre A e B
+    require!(bet.outcome_count == 2, BetError::InvalidOracleTerms);
+    // O feed precisa ser um price account do Pyth já no registro do Bet
+    let price = load_pyth_price(&ctx.accounts.price_feed.to_account_info())?;
+    require!(price.expo == strike_expo, BetError::InvalidPriceFeed);
+    require!(
+        max_price_age > 0 && max_confidence_bps > 0 && max_confidence_bps <= 10_000,
+        BetError::InvalidOracleTerms
+    );
+
+    bet.oracle_feed = price_feed;
+    bet.strike_price = strike_price;
+    bet.strike_expo = strike_expo;
+    bet.price_comparison = comparison;
+    bet.max_price_age = max_price_age;
+    bet.max_confidence_bps = max_confidence_bps;
+
+    emit!(OracleSet {
+        bet: bet.key(),
+        price_feed,
+        strike_price,
+        strike_expo,
+        comparison,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct OracleSet {
+    pub bet: Pubkey,
+    pub price_feed: Pubkey,
+    pub strike_price: i64,
+    pub strike_expo: i32,
+    pub comparison: PriceComparison,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::ProtocolConfig;
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BetError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+/// Pausa bloqueia o dinheiro novo: `create_bet`, `deposit_participant`,
+/// `accept_challenge`, `support_bet`, `dispute_result`, `post_arbiter_bond` e
+/// `fund_prize_pool`. Resolução, saques e reembolsos continuam disponíveis.
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.config.paused = paused;
+
+    emit!(PauseUpdated {
+        admin: ctx.accounts.admin.key(),
+        paused,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub admin: Pubkey,
+    pub paused: bool,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, MAX_SERIES_LENGTH};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetSeries<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Transforma o duelo em série melhor-de-N: o árbitro registra cada jogo com
+/// `record_game` e o Bet resolve sozinho quando um lado atinge a maioria.
+/// Só pode ser configurada antes dos depósitos, para os duelistas aceitarem o formato.
+pub fn handler(ctx: Context<SetSeries>, best_of: u8) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+
+    // Validações
+    require!(
+        ctx.accounts.payer.key() == bet.payer,
+        BetError::InvalidPayer
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+    require!(bet.series_length == 0, BetError::InvalidSeries);
+    require!(!bet.has_committee() && !bet.has_oracle() && !bet.has_attestor(), BetError::InvalidSeries);
+    // Número ímpar de jogos: sempre há maioria
+    require!(
+        (3..=MAX_SERIES_LENGTH).contains(&best_of) && best_of % 2 == 1,
+        BetError::InvalidSeries
+    );
+
+    bet.series_length = best_of;
+
+    emit!(SeriesSet {
+        bet: bet.key(),
+        best_of,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SeriesSet {
+    pub bet: Pubkey,
+    pub best_of: u8,
+}
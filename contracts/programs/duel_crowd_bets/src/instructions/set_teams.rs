@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, BetStatus, RosterEntry, TeamMember, MAX_TEAM_SIZE};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SetTeams<'info> {
+    pub user_a: Signer<'info>,
+
+    pub user_b: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+}
+
+/// Transforma o duelo em disputa entre equipes. `user_a` e `user_b` seguem
+/// como capitães (seeds e `mutual_cancel`); quem deposita e recebe são os
+/// membros dos rosters, e o stake de cada lado passa a ser a soma do time.
+/// Os dois capitães assinam: o roster define quanto cada membro deposita.
+pub fn handler(
+    ctx: Context<SetTeams>,
+    team_a: Vec<RosterEntry>,
+    team_b: Vec<RosterEntry>,
+) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let clock = Clock::get()?;
+
+    // Validações
+    require!(
+        ctx.accounts.user_a.key() == bet.user_a,
+        BetError::InvalidParticipant
+    );
+    require!(
+        ctx.accounts.user_b.key() == bet.user_b,
+        BetError::InvalidParticipant
+    );
+    require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+    require!(
+        clock.unix_timestamp < bet.deadline_duel,
+        BetError::DeadlinePassed
+    );
+    require!(
+        !bet.is_open_challenge() && !bet.has_teams(),
+        BetError::InvalidRoster
+    );
+    require!(
+        !bet.user_a_deposited && !bet.user_b_deposited,
+        BetError::AlreadyDeposited
+    );
+
+    for team in [&team_a, &team_b] {
+        require!(
+            !team.is_empty() && team.len() <= MAX_TEAM_SIZE,
+            BetError::InvalidRoster
+        );
+        require!(
+            team.iter().all(|entry| entry.stake > 0),
+            BetError::InvalidStakeAmount
+        );
+    }
+
+    // Ninguém pode aparecer duas vezes, nem em lados opostos
+    let all_members: Vec<Pubkey> = team_a.iter().chain(team_b.iter()).map(|e| e.member).collect();
+    for (i, member) in all_members.iter().enumerate() {
+        require!(
+            !all_members[i + 1..].contains(member),
+            BetError::InvalidRoster
+        );
+        // Nem votar no comitê ou julgar a disputa que decide o próprio stake
+        require!(
+            bet.committee.iter().all(|c| c.member != *member)
+                && *member != bet.fallback_arbiter,
+            BetError::InvalidRoster
+        );
+    }
+
+    let to_roster = |team: &[RosterEntry]| -> Vec<TeamMember> {
+        team.iter()
+            .map(|entry| TeamMember {
+                member: entry.member,
+                stake: entry.stake,
+                principal: 0,
+                deposited: false,
+                principal_claimed: false,
+                spread_claimed: false,
+            })
+            .collect()
+    };
+    let sum_stakes = |team: &[RosterEntry]| -> Option<u64> {
+        team.iter().try_fold(0u64, |acc, entry| acc.checked_add(entry.stake))
+    };
+
+    bet.stake_a = sum_stakes(&team_a).ok_or(BetError::ArithmeticOverflow)?;
+    bet.stake_b = sum_stakes(&team_b).ok_or(BetError::ArithmeticOverflow)?;
+    bet.team_a = to_roster(&team_a);
+    bet.team_b = to_roster(&team_b);
+
+    emit!(TeamsSet {
+        bet: bet.key(),
+        members_a: team_a.iter().map(|e| e.member).collect(),
+        members_b: team_b.iter().map(|e| e.member).collect(),
+        stake_a: bet.stake_a,
+        stake_b: bet.stake_b,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TeamsSet {
+    pub bet: Pubkey,
+    pub members_a: Vec<Pubkey>,
+    pub members_b: Vec<Pubkey>,
+    pub stake_a: u64,
+    pub stake_b: u64,
+}
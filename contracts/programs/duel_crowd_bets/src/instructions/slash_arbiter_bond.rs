@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::{ArbiterBond, Bet, BetStatus, DisputeStatus, Side};
+use crate::errors::BetError;
+
+#[derive(Accounts)]
+pub struct SlashArbiterBond<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Só o bond é confiscado; o rent do escrow volta ao árbitro
+    #[account(
+        mut,
+        close = arbiter,
+        seeds = [b"arbiter_bond", bet.key().as_ref()],
+        bump = arbiter_bond.bump
+    )]
+    pub arbiter_bond: Account<'info, ArbiterBond>,
+
+    /// CHECK: Validated in handler against `bet.arbiter`
+    #[account(mut)]
+    pub arbiter: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in handler against `bet.user_a`
+    #[account(mut)]
+    pub user_a: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in handler against `bet.user_b`
+    #[account(mut)]
+    pub user_b: UncheckedAccount<'info>,
+}
+
+/// Permissionless. O bond vai para os duelistas prejudicados: os dois lados,
+/// metade cada, se o árbitro deixou o duelo fundado expirar; o lado que
+/// venceu após a disputa (ou os dois, em empate) se o resultado foi revertido.
+/// Em duelos de equipe o pagamento vai para os capitães (`user_a`/`user_b`).
+///
+/// A torcida não entra na divisão porque já sai inteira nos dois casos: na
+/// expiração `claim_support` devolve o valor bruto, fee incluído, e na reversão
+/// ela é paga pelo resultado corrigido. O bond é a garantia de quem escolheu
+/// o árbitro e pôs o stake no duelo.
+pub fn handler(ctx: Context<SlashArbiterBond>) -> Result<()> {
+    let (amount_a, amount_b);
+    {
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            ctx.accounts.arbiter.key() == bet.arbiter,
+            BetError::InvalidArbiter
+        );
+        require!(
+            ctx.accounts.user_a.key() == bet.user_a && ctx.accounts.user_b.key() == bet.user_b,
+            BetError::InvalidRecipient
+        );
+        require!(!bet.arbiter_bond_settled, BetError::CannotSlashBond);
+
+        let funded = bet.user_a_deposited && bet.user_b_deposited;
+        let missed_deadline = bet.status == BetStatus::Expired && funded;
+        let overturned = bet.dispute_status == DisputeStatus::Overturned;
+        require!(missed_deadline || overturned, BetError::CannotSlashBond);
+
+        let bond = ctx.accounts.arbiter_bond.amount;
+        let half = bond / 2;
+        (amount_a, amount_b) = match (overturned, bet.winner_side) {
+            (true, Some(Side::A)) => (bond, 0),
+            (true, Some(Side::B)) => (0, bond),
+            _ => (half, bond - half),
+        };
+
+        bet.arbiter_bond_settled = true;
+    }
+
+    // Transfer do escrow para os duelistas
+    let escrow = ctx.accounts.arbiter_bond.to_account_info();
+    **escrow.try_borrow_mut_lamports()? -= amount_a + amount_b;
+    **ctx.accounts.user_a.try_borrow_mut_lamports()? += amount_a;
+    **ctx.accounts.user_b.try_borrow_mut_lamports()? += amount_b;
+
+    emit!(ArbiterBondSlashed {
+        bet: ctx.accounts.bet.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+        amount_a,
+        amount_b,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ArbiterBondSlashed {
+    pub bet: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount_a: u64,
+    pub amount_b: u64,
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{Bet, BetStatus, Side, SupportPosition};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Bet, BetStatus, ProtocolConfig, SupportPosition, Tournament};
 use crate::errors::BetError;
+use crate::vault::deposit_to_bet;
 
 #[derive(Accounts)]
-#[instruction(side: Side)]
+#[instruction(outcome: u8)]
 pub struct SupportBet<'info> {
     #[account(mut)]
     pub bettor: Signer<'info>,
@@ -13,14 +14,16 @@ pub struct SupportBet<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
     pub bet: Account<'info, Bet>,
 
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
     #[account(
         init_if_needed,
         payer = bettor,
@@ -29,45 +32,90 @@ pub struct SupportBet<'info> {
             b"support",
             bet.key().as_ref(),
             bettor.key().as_ref(),
-            &[match side {
-                Side::A => 0,
-                Side::B => 1,
-            }],
+            &[outcome],
         ],
         bump
     )]
     pub support_position: Account<'info, SupportPosition>,
 
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bettor_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    // Obrigatório no mercado do campeão
+    pub tournament: Option<Account<'info, Tournament>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<SupportBet>, outcome: u8, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
 
     // Read-only validations
     {
+        require!(!ctx.accounts.config.paused, BetError::ProgramPaused);
+
         let bet = &ctx.accounts.bet;
         require!(bet.status == BetStatus::Open, BetError::BetNotOpen);
+        // O mercado do campeão não tem duelo próprio para ser fundado
         require!(
-            bet.user_a_deposited && bet.user_b_deposited,
+            bet.is_champion_market() || (bet.user_a_deposited && bet.user_b_deposited),
             BetError::ParticipantsNotDeposited
         );
         require!(
             clock.unix_timestamp < bet.deadline_crowd,
             BetError::DeadlinePassed
         );
+        require!(outcome < bet.outcome_count, BetError::InvalidOutcome);
+        // A torcida só abre depois que o árbitro tem algo em jogo
+        require!(
+            bet.arbiter_bond == 0 || bet.arbiter_bond_posted,
+            BetError::ArbiterBondNotPosted
+        );
+        // Mercado do campeão: fecha quando a final define o campeão, mesmo
+        // antes de deadline_crowd, senão dava para apostar no resultado já conhecido
+        if bet.is_champion_market() {
+            let tournament = ctx.accounts.tournament
+                .as_ref()
+                .ok_or(BetError::InvalidTournament)?;
+            require!(
+                tournament.key() == bet.tournament,
+                BetError::InvalidTournament
+            );
+            require!(tournament.champion.is_none(), BetError::TournamentFinished);
+        }
     }
     require!(amount > 0, BetError::AmountTooSmall);
 
-    // Calcular fees (using immutable bet reference)
-    let fee_total = amount
+    // Transfer amount to bet PDA (ou vault, em bets de token)
+    let received = deposit_to_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        &ctx.accounts.bettor,
+        ctx.accounts.bettor_token.as_ref(),
+        amount,
+    )?;
+
+    // Calcular fees sobre o valor recebido (using immutable bet reference)
+    let fee_total = received
         .checked_mul(ctx.accounts.bet.spread_bps as u64)
         .and_then(|v| v.checked_div(10_000))
         .ok_or(BetError::ArithmeticOverflow)?;
 
-    let net = amount
+    let net = received
         .checked_sub(fee_total)
         .ok_or(BetError::ArithmeticOverflow)?;
+    require!(net > 0, BetError::AmountTooSmall);
 
     let fee_creators = fee_total
         .checked_mul(ctx.accounts.bet.creator_share_bps as u64)
@@ -84,34 +132,14 @@ pub fn handler(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()>
         .and_then(|v| v.checked_div(10_000))
         .ok_or(BetError::ArithmeticOverflow)?;
 
-    // Transfer amount to bet PDA
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.bettor.to_account_info(),
-                to: ctx.accounts.bet.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
-
     // Mutate pools after transfer
     let bet = &mut ctx.accounts.bet;
 
     // Atualizar pools
-    match side {
-        Side::A => {
-            bet.net_support_a = bet.net_support_a
-                .checked_add(net)
-                .ok_or(BetError::ArithmeticOverflow)?;
-        }
-        Side::B => {
-            bet.net_support_b = bet.net_support_b
-                .checked_add(net)
-                .ok_or(BetError::ArithmeticOverflow)?;
-        }
-    }
+    let pool = &mut bet.net_support[outcome as usize];
+    *pool = pool
+        .checked_add(net)
+        .ok_or(BetError::ArithmeticOverflow)?;
 
     bet.spread_pool_creators = bet.spread_pool_creators
         .checked_add(fee_creators)
@@ -129,10 +157,15 @@ pub fn handler(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()>
     let support_position = &mut ctx.accounts.support_position;
     if support_position.net_amount == 0 {
         // Nova posição
+        bet.open_positions = bet.open_positions
+            .checked_add(1)
+            .ok_or(BetError::ArithmeticOverflow)?;
+
         support_position.bet = ctx.accounts.bet.key();
         support_position.bettor = ctx.accounts.bettor.key();
-        support_position.side = side;
+        support_position.outcome = outcome;
         support_position.net_amount = net;
+        support_position.gross_amount = received;
         support_position.claimed = false;
         support_position.bump = ctx.bumps.support_position;
     } else {
@@ -140,12 +173,15 @@ pub fn handler(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()>
         support_position.net_amount = support_position.net_amount
             .checked_add(net)
             .ok_or(BetError::ArithmeticOverflow)?;
+        support_position.gross_amount = support_position.gross_amount
+            .checked_add(received)
+            .ok_or(BetError::ArithmeticOverflow)?;
     }
 
     emit!(BetSupported {
         bet: ctx.accounts.bet.key(),
         bettor: ctx.accounts.bettor.key(),
-        side,
+        outcome,
         amount,
         net_amount: net,
     });
@@ -157,7 +193,7 @@ pub fn handler(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()>
 pub struct BetSupported {
     pub bet: Pubkey,
     pub bettor: Pubkey,
-    pub side: Side,
+    pub outcome: u8,
     pub amount: u64,
     pub net_amount: u64,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::ProtocolConfig;
+use crate::errors::BetError;
+use crate::instructions::initialize_config::ConfigUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BetError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateConfig>,
+    new_admin: Pubkey,
+    treasury: Pubkey,
+    min_spread_bps: u16,
+    max_spread_bps: u16,
+    min_protocol_share_bps: u16,
+) -> Result<()> {
+    // Validações
+    require!(
+        ProtocolConfig::validate_limits(min_spread_bps, max_spread_bps, min_protocol_share_bps),
+        BetError::InvalidFeeConfig
+    );
+
+    // Bets já criados mantêm treasury e fees com que foram criados
+    let config = &mut ctx.accounts.config;
+    config.admin = new_admin;
+    config.treasury = treasury;
+    config.min_spread_bps = min_spread_bps;
+    config.max_spread_bps = max_spread_bps;
+    config.min_protocol_share_bps = min_protocol_share_bps;
+
+    emit!(ConfigUpdated {
+        admin: new_admin,
+        treasury,
+        min_spread_bps,
+        max_spread_bps,
+        min_protocol_share_bps,
+    });
+
+    Ok(())
+}
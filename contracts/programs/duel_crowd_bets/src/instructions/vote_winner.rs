@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{Bet, Tournament};
+use crate::errors::BetError;
+use crate::instructions::declare_winner::{apply_result, check_resolution_window};
+
+#[derive(Accounts)]
+pub struct VoteWinner<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bet",
+            bet.user_a.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
+        ],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+
+    // Obrigatório quando o Bet é um match de torneio
+    #[account(mut)]
+    pub tournament: Option<Account<'info, Tournament>>,
+}
+
+/// Voto de um membro do comitê; `outcome = None` vota empate. O voto que
+/// fecha o threshold resolve o Bet como `declare_winner`.
+pub fn handler(ctx: Context<VoteWinner>, outcome: Option<u8>) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    let member_key = ctx.accounts.member.key();
+
+    // Validações
+    require!(bet.has_committee(), BetError::InvalidCommittee);
+    check_resolution_window(bet)?;
+    if let Some(outcome) = outcome {
+        require!(outcome < bet.outcome_count, BetError::InvalidOutcome);
+    }
+
+    let member = bet.committee
+        .iter_mut()
+        .find(|m| m.member == member_key)
+        .ok_or(BetError::InvalidArbiter)?;
+    require!(!member.voted, BetError::AlreadyVoted);
+    member.voted = true;
+    member.vote = outcome;
+
+    let votes = bet.committee
+        .iter()
+        .filter(|m| m.voted && m.vote == outcome)
+        .count() as u8;
+
+    emit!(VoteCast {
+        bet: bet.key(),
+        member: member_key,
+        outcome,
+        votes,
+    });
+
+    if votes >= bet.committee_threshold {
+        apply_result(bet, ctx.accounts.tournament.as_mut(), outcome)?;
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct VoteCast {
+    pub bet: Pubkey,
+    pub member: Pubkey,
+    pub outcome: Option<u8>,
+    pub votes: u8,          // votos acumulados nesse outcome
+}
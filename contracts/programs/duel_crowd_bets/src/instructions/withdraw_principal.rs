@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::{Bet, BetStatus, Side};
 use crate::errors::BetError;
+use crate::vault::pay_from_bet;
+use crate::instructions::refund_participant::ParticipantRefunded;
 
 #[derive(Accounts)]
 pub struct WithdrawPrincipal<'info> {
@@ -11,17 +14,39 @@ pub struct WithdrawPrincipal<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
     pub bet: Account<'info, Bet>,
+
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub winner_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<WithdrawPrincipal>) -> Result<()> {
-    let bet = &ctx.accounts.bet;
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts.bet.is_result_final(clock.unix_timestamp),
+        BetError::ResultNotFinal
+    );
+
+    // Empate, outcome sem duelista vencedor, ou árbitro não resolveu a tempo:
+    // cada participante recupera o próprio stake
+    if ctx.accounts.bet.returns_stakes() {
+        return refund_stake(ctx);
+    }
+
+    let bet = &mut ctx.accounts.bet;
 
     // Validações
     require!(
@@ -30,34 +55,60 @@ pub fn handler(ctx: Context<WithdrawPrincipal>) -> Result<()> {
     );
 
     let winner_side = bet.winner_side.ok_or(BetError::BetNotResolved)?;
+    let claim_flag = Bet::principal_claim_flag(winner_side);
 
-    let expected_winner = match winner_side {
-        Side::A => bet.user_a,
-        Side::B => bet.user_b,
-    };
+    // Pote do duelo: os dois stakes, como de fato entraram no Bet
+    let pot = bet.principal_a
+        .checked_add(bet.principal_b)
+        .ok_or(BetError::ArithmeticOverflow)?;
 
-    require!(
-        ctx.accounts.winner.key() == expected_winner,
-        BetError::InvalidWinner
-    );
+    let amount = if bet.has_teams() {
+        // Time vencedor divide o pote pro-rata ao que cada membro depositou
+        let index = bet.team(winner_side)
+            .iter()
+            .position(|m| m.member == ctx.accounts.winner.key())
+            .ok_or(BetError::InvalidWinner)?;
+        let team_principal = bet.principal_of(winner_side);
+        let member = &mut bet.team_mut(winner_side)[index];
+        require!(!member.principal_claimed, BetError::PrincipalAlreadyClaimed);
+        member.principal_claimed = true;
 
-    // Calcular payout (2x o stake)
-    let amount = bet.stake_lamports
-        .checked_mul(2)
-        .ok_or(BetError::ArithmeticOverflow)?;
+        let amount = (pot as u128)
+            .checked_mul(member.principal as u128)
+            .and_then(|v| v.checked_div(team_principal as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(BetError::ArithmeticOverflow)?;
+
+        if bet.principal_returned(winner_side) {
+            bet.settlement_claims |= claim_flag;
+        }
+        amount
+    } else {
+        let expected_winner = match winner_side {
+            Side::A => bet.user_a,
+            Side::B => bet.user_b,
+        };
+
+        require!(
+            ctx.accounts.winner.key() == expected_winner,
+            BetError::InvalidWinner
+        );
+
+        require!(!bet.is_claimed(claim_flag), BetError::PrincipalAlreadyClaimed);
+        bet.settlement_claims |= claim_flag;
+        pot
+    };
 
     // Transfer do PDA para o vencedor
-    let seeds = &[
-        b"bet",
-        bet.arbiter.as_ref(),
-        bet.user_a.as_ref(),
-        bet.user_b.as_ref(),
-        &[bet.bump],
-    ];
-    let signer = &[&seeds[..]];
-
-    **ctx.accounts.bet.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.winner.to_account_info().try_borrow_mut_lamports()? += amount;
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.winner.to_account_info(),
+        ctx.accounts.winner_token.as_ref(),
+        amount,
+    )?;
 
     emit!(PrincipalWithdrawn {
         bet: ctx.accounts.bet.key(),
@@ -68,6 +119,28 @@ pub fn handler(ctx: Context<WithdrawPrincipal>) -> Result<()> {
     Ok(())
 }
 
+fn refund_stake(ctx: Context<WithdrawPrincipal>) -> Result<()> {
+    let amount = ctx.accounts.bet.take_stake_refund(ctx.accounts.winner.key())?;
+
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.winner.to_account_info(),
+        ctx.accounts.winner_token.as_ref(),
+        amount,
+    )?;
+
+    emit!(ParticipantRefunded {
+        bet: ctx.accounts.bet.key(),
+        participant: ctx.accounts.winner.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
 #[event]
 pub struct PrincipalWithdrawn {
     pub bet: Pubkey,
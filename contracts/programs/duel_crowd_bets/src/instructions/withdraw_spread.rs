@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{Bet, BetStatus};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{ArbiterProfile, Bet, BetStatus, Side, SpreadRecipient};
 use crate::errors::BetError;
+use crate::vault::pay_from_bet;
 
 #[derive(Accounts)]
 pub struct WithdrawSpread<'info> {
@@ -11,91 +13,139 @@ pub struct WithdrawSpread<'info> {
         mut,
         seeds = [
             b"bet",
-            bet.arbiter.as_ref(),
             bet.user_a.as_ref(),
-            bet.user_b.as_ref(),
+            bet.bet_id.to_le_bytes().as_ref(),
         ],
         bump = bet.bump
     )]
     pub bet: Account<'info, Bet>,
 
-    /// CHECK: User A receiving fee share
+    /// CHECK: Validated in handler against the address stored on `bet` for `share`
     #[account(mut)]
-    pub user_a: UncheckedAccount<'info>,
+    pub recipient: UncheckedAccount<'info>,
 
-    /// CHECK: User B receiving fee share
-    #[account(mut)]
-    pub user_b: UncheckedAccount<'info>,
+    #[account(address = bet.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [b"vault", bet.key().as_ref()], bump)]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CHECK: Arbiter receiving fee share
     #[account(mut)]
-    pub arbiter: UncheckedAccount<'info>,
+    pub recipient_token: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 
-    /// CHECK: Protocol treasury receiving fee share
+    // Obrigatório quando o Bet rastreia o histórico do árbitro
     #[account(mut)]
-    pub protocol_treasury: UncheckedAccount<'info>,
+    pub arbiter_profile: Option<Account<'info, ArbiterProfile>>,
 }
 
-pub fn handler(ctx: Context<WithdrawSpread>) -> Result<()> {
-    // Validations
-    require!(
-        ctx.accounts.bet.status == BetStatus::Resolved,
-        BetError::BetNotResolved
-    );
-
-    // Snapshot values immutably
-    let spread_creators = ctx.accounts.bet.spread_pool_creators;
-    let fee_a = spread_creators / 2;
-    let fee_b = spread_creators - fee_a;
-    let fee_arbiter = ctx.accounts.bet.spread_pool_arbiter;
-    let fee_protocol = ctx.accounts.bet.spread_pool_protocol;
-
-    let total_spread = spread_creators
-        .checked_add(fee_arbiter)
-        .and_then(|v| v.checked_add(fee_protocol))
-        .ok_or(BetError::ArithmeticOverflow)?;
-
-    if total_spread == 0 {
-        return Ok(());
-    }
-
-    // Perform transfers
+pub fn handler(ctx: Context<WithdrawSpread>, share: SpreadRecipient) -> Result<()> {
+    let amount;
     {
-        let mut bet_info = ctx.accounts.bet.to_account_info();
-
-        if fee_a > 0 {
-            **bet_info.try_borrow_mut_lamports()? -= fee_a;
-            **ctx.accounts.user_a.to_account_info().try_borrow_mut_lamports()? += fee_a;
-        }
-
-        if fee_b > 0 {
-            **bet_info.try_borrow_mut_lamports()? -= fee_b;
-            **ctx.accounts.user_b.to_account_info().try_borrow_mut_lamports()? += fee_b;
-        }
-
-        if fee_arbiter > 0 {
-            **bet_info.try_borrow_mut_lamports()? -= fee_arbiter;
-            **ctx.accounts.arbiter.to_account_info().try_borrow_mut_lamports()? += fee_arbiter;
-        }
-
-        if fee_protocol > 0 {
-            **bet_info.try_borrow_mut_lamports()? -= fee_protocol;
-            **ctx.accounts.protocol_treasury.to_account_info().try_borrow_mut_lamports()? += fee_protocol;
+        let bet = &mut ctx.accounts.bet;
+
+        // Validações
+        require!(
+            matches!(bet.status, BetStatus::Resolved | BetStatus::Draw),
+            BetError::BetNotResolved
+        );
+        require!(
+            bet.is_result_final(Clock::get()?.unix_timestamp),
+            BetError::ResultNotFinal
+        );
+
+        let claim_flag = Bet::spread_claim_flag(share);
+        require!(!bet.is_claimed(claim_flag), BetError::SpreadAlreadyClaimed);
+        // Com bond, o fee do árbitro sai junto com a devolução do bond
+        require!(
+            !(share == SpreadRecipient::Arbiter && bet.arbiter_bond_posted),
+            BetError::ArbiterFeeWithBond
+        );
+
+        let team_side = match share {
+            SpreadRecipient::CreatorA if bet.has_teams() => Some(Side::A),
+            SpreadRecipient::CreatorB if bet.has_teams() => Some(Side::B),
+            _ => None,
+        };
+
+        if share == SpreadRecipient::Arbiter && bet.has_committee() {
+            // Comitê: cada membro que votou com a maioria saca a sua parte
+            let per_voter = bet.arbiter_fee_per_voter();
+            let winning_outcome = bet.winning_outcome;
+            let member = bet.committee
+                .iter_mut()
+                .find(|m| m.member == ctx.accounts.recipient.key())
+                .ok_or(BetError::InvalidRecipient)?;
+            require!(
+                member.voted && member.vote == winning_outcome,
+                BetError::InvalidRecipient
+            );
+            require!(!member.fee_claimed, BetError::SpreadAlreadyClaimed);
+            member.fee_claimed = true;
+
+            if bet.committee
+                .iter()
+                .all(|m| m.fee_claimed || !(m.voted && m.vote == winning_outcome))
+            {
+                bet.settlement_claims |= claim_flag;
+            }
+            amount = per_voter;
+        } else if let Some(side) = team_side {
+            // Duelo em equipe: cada membro do roster saca a sua parte da fatia dos criadores
+            let per_member = bet.creator_spread_per_member();
+            let index = bet.team(side)
+                .iter()
+                .position(|m| m.member == ctx.accounts.recipient.key())
+                .ok_or(BetError::InvalidRecipient)?;
+            let member = &mut bet.team_mut(side)[index];
+            require!(!member.spread_claimed, BetError::SpreadAlreadyClaimed);
+            member.spread_claimed = true;
+
+            if bet.team(side).iter().all(|m| m.spread_claimed) {
+                bet.settlement_claims |= claim_flag;
+            }
+            amount = per_member;
+        } else {
+            let expected_recipient = match share {
+                SpreadRecipient::CreatorA => bet.user_a,
+                SpreadRecipient::CreatorB => bet.user_b,
+                SpreadRecipient::Arbiter => bet.arbiter,
+                SpreadRecipient::Protocol => bet.protocol_treasury,
+            };
+            require!(
+                ctx.accounts.recipient.key() == expected_recipient,
+                BetError::InvalidRecipient
+            );
+
+            bet.settlement_claims |= claim_flag;
+
+            // Os pools não são zerados: cada parte saca a sua fatia uma única vez
+            amount = bet.spread_share_amount(share);
+
+            if share == SpreadRecipient::Arbiter {
+                if let Some(profile) = bet.tracked_profile(ctx.accounts.arbiter_profile.as_mut())? {
+                    profile.record_fee(bet, amount)?;
+                }
+            }
         }
     }
 
-    // Zero pools after transfers
-    let bet = &mut ctx.accounts.bet;
-    bet.spread_pool_creators = 0;
-    bet.spread_pool_arbiter = 0;
-    bet.spread_pool_protocol = 0;
+    pay_from_bet(
+        &ctx.accounts.bet,
+        ctx.accounts.mint.as_ref(),
+        ctx.accounts.vault.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.recipient.to_account_info(),
+        ctx.accounts.recipient_token.as_ref(),
+        amount,
+    )?;
 
     emit!(SpreadWithdrawn {
         bet: ctx.accounts.bet.key(),
-        fee_a,
-        fee_b,
-        fee_arbiter,
-        fee_protocol,
+        recipient: ctx.accounts.recipient.key(),
+        share,
+        amount,
     });
 
     Ok(())
@@ -104,8 +154,7 @@ pub fn handler(ctx: Context<WithdrawSpread>) -> Result<()> {
 #[event]
 pub struct SpreadWithdrawn {
     pub bet: Pubkey,
-    pub fee_a: u64,
-    pub fee_b: u64,
-    pub fee_arbiter: u64,
-    pub fee_protocol: u64,
+    pub recipient: Pubkey,
+    pub share: SpreadRecipient,
+    pub amount: u64,
 }
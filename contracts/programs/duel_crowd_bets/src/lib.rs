@@ -3,9 +3,12 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod errors;
 pub mod instructions;
+pub mod vault;
+pub mod oracle;
+pub mod attestation;
 
 use instructions::*;
-use state::Side;
+use state::{CreateBetParams, PriceComparison, RosterEntry, Side, SpreadRecipient};
 
 declare_id!("5iRExHjkQzwidM7EwCu8eVpeBAPnJ8qVuHi3y7gZbaeX");
 
@@ -18,41 +21,125 @@ pub mod duel_crowd_bets {
         user_a: Pubkey,
         user_b: Pubkey,
         arbiter: Pubkey,
-        stake_lamports: u64,
-        deadline_duel: i64,
-        deadline_crowd: i64,
-        resolve_ts: i64,
-        spread_bps: u16,
-        creator_share_bps: u16,
-        arbiter_share_bps: u16,
-        protocol_share_bps: u16,
+        bet_id: u64,
+        params: CreateBetParams,
     ) -> Result<()> {
-        instructions::create_bet::handler(
-            ctx,
-            user_a,
-            user_b,
-            arbiter,
-            stake_lamports,
-            deadline_duel,
-            deadline_crowd,
-            resolve_ts,
-            spread_bps,
-            creator_share_bps,
-            arbiter_share_bps,
-            protocol_share_bps,
-        )
+        instructions::create_bet::handler(ctx, user_a, user_b, arbiter, bet_id, params)
+    }
+
+    pub fn set_teams(
+        ctx: Context<SetTeams>,
+        team_a: Vec<RosterEntry>,
+        team_b: Vec<RosterEntry>,
+    ) -> Result<()> {
+        instructions::set_teams::handler(ctx, team_a, team_b)
     }
 
     pub fn deposit_participant(ctx: Context<DepositParticipant>) -> Result<()> {
         instructions::deposit_participant::handler(ctx)
     }
 
-    pub fn support_bet(ctx: Context<SupportBet>, side: Side, amount: u64) -> Result<()> {
-        instructions::support_bet::handler(ctx, side, amount)
+    pub fn accept_challenge(ctx: Context<AcceptChallenge>) -> Result<()> {
+        instructions::accept_challenge::handler(ctx)
+    }
+
+    pub fn support_bet(ctx: Context<SupportBet>, outcome: u8, amount: u64) -> Result<()> {
+        instructions::support_bet::handler(ctx, outcome, amount)
+    }
+
+    pub fn declare_winner(ctx: Context<DeclareWinner>, outcome: Option<u8>) -> Result<()> {
+        instructions::declare_winner::handler(ctx, outcome)
+    }
+
+    pub fn set_series(ctx: Context<SetSeries>, best_of: u8) -> Result<()> {
+        instructions::set_series::handler(ctx, best_of)
+    }
+
+    pub fn record_game(ctx: Context<RecordGame>, winner_side: Side) -> Result<()> {
+        instructions::record_game::handler(ctx, winner_side)
+    }
+
+    pub fn set_committee(
+        ctx: Context<SetCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_committee::handler(ctx, members, threshold)
+    }
+
+    pub fn vote_winner(ctx: Context<VoteWinner>, outcome: Option<u8>) -> Result<()> {
+        instructions::vote_winner::handler(ctx, outcome)
+    }
+
+    pub fn set_oracle(
+        ctx: Context<SetOracle>,
+        strike_price: i64,
+        strike_expo: i32,
+        comparison: PriceComparison,
+        max_price_age: i64,
+        max_confidence_bps: u16,
+    ) -> Result<()> {
+        instructions::set_oracle::handler(
+            ctx,
+            strike_price,
+            strike_expo,
+            comparison,
+            max_price_age,
+            max_confidence_bps,
+        )
+    }
+
+    pub fn resolve_with_oracle(ctx: Context<ResolveWithOracle>) -> Result<()> {
+        instructions::resolve_with_oracle::handler(ctx)
+    }
+
+    pub fn set_attestor(ctx: Context<SetAttestor>, attestor: Pubkey) -> Result<()> {
+        instructions::set_attestor::handler(ctx, attestor)
+    }
+
+    pub fn resolve_with_attestation(
+        ctx: Context<ResolveWithAttestation>,
+        winner_side: Side,
+        timestamp: i64,
+    ) -> Result<()> {
+        instructions::resolve_with_attestation::handler(ctx, winner_side, timestamp)
+    }
+
+    pub fn set_dispute_terms(
+        ctx: Context<SetDisputeTerms>,
+        fallback_arbiter: Pubkey,
+        dispute_window: i64,
+        dispute_bond: u64,
+    ) -> Result<()> {
+        instructions::set_dispute_terms::handler(ctx, fallback_arbiter, dispute_window, dispute_bond)
+    }
+
+    pub fn dispute_result(ctx: Context<DisputeResult>) -> Result<()> {
+        instructions::dispute_result::handler(ctx)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, outcome: Option<u8>) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, outcome)
+    }
+
+    pub fn expire_dispute(ctx: Context<ExpireDispute>) -> Result<()> {
+        instructions::expire_dispute::handler(ctx)
     }
 
-    pub fn declare_winner(ctx: Context<DeclareWinner>, winner_side: Side) -> Result<()> {
-        instructions::declare_winner::handler(ctx, winner_side)
+    pub fn set_arbiter_bond(ctx: Context<SetArbiterBond>, amount: u64) -> Result<()> {
+        instructions::set_arbiter_bond::handler(ctx, amount)
+    }
+
+    pub fn post_arbiter_bond(ctx: Context<PostArbiterBond>) -> Result<()> {
+        instructions::post_arbiter_bond::handler(ctx)
+    }
+
+    pub fn release_arbiter_bond(ctx: Context<ReleaseArbiterBond>) -> Result<()> {
+        instructions::release_arbiter_bond::handler(ctx)
+    }
+
+    pub fn slash_arbiter_bond(ctx: Context<SlashArbiterBond>) -> Result<()> {
+        instructions::slash_arbiter_bond::handler(ctx)
     }
 
     pub fn withdraw_principal(ctx: Context<WithdrawPrincipal>) -> Result<()> {
@@ -63,7 +150,106 @@ pub mod duel_crowd_bets {
         instructions::claim_support::handler(ctx)
     }
 
-    pub fn withdraw_spread(ctx: Context<WithdrawSpread>) -> Result<()> {
-        instructions::withdraw_spread::handler(ctx)
+    pub fn withdraw_spread(ctx: Context<WithdrawSpread>, share: SpreadRecipient) -> Result<()> {
+        instructions::withdraw_spread::handler(ctx, share)
+    }
+
+    pub fn cancel_bet(ctx: Context<CancelBet>) -> Result<()> {
+        instructions::cancel_bet::handler(ctx)
+    }
+
+    pub fn refund_participant(ctx: Context<RefundParticipant>) -> Result<()> {
+        instructions::refund_participant::handler(ctx)
+    }
+
+    pub fn refund_support(ctx: Context<RefundSupport>) -> Result<()> {
+        instructions::refund_support::handler(ctx)
+    }
+
+    pub fn expire_bet(ctx: Context<ExpireBet>) -> Result<()> {
+        instructions::expire_bet::handler(ctx)
+    }
+
+    pub fn mutual_cancel(ctx: Context<MutualCancel>) -> Result<()> {
+        instructions::mutual_cancel::handler(ctx)
+    }
+
+    pub fn reassign_arbiter(ctx: Context<ReassignArbiter>, new_arbiter: Pubkey) -> Result<()> {
+        instructions::reassign_arbiter::handler(ctx, new_arbiter)
+    }
+
+    pub fn close_support_position(ctx: Context<CloseSupportPosition>) -> Result<()> {
+        instructions::close_support_position::handler(ctx)
+    }
+
+    pub fn close_bet(ctx: Context<CloseBet>) -> Result<()> {
+        instructions::close_bet::handler(ctx)
+    }
+
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury: Pubkey,
+        min_spread_bps: u16,
+        max_spread_bps: u16,
+        min_protocol_share_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(
+            ctx,
+            treasury,
+            min_spread_bps,
+            max_spread_bps,
+            min_protocol_share_bps,
+        )
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Pubkey,
+        treasury: Pubkey,
+        min_spread_bps: u16,
+        max_spread_bps: u16,
+        min_protocol_share_bps: u16,
+    ) -> Result<()> {
+        instructions::update_config::handler(
+            ctx,
+            new_admin,
+            treasury,
+            min_spread_bps,
+            max_spread_bps,
+            min_protocol_share_bps,
+        )
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::handler(ctx, paused)
+    }
+
+    pub fn register_arbiter(ctx: Context<RegisterArbiter>) -> Result<()> {
+        instructions::register_arbiter::handler(ctx)
+    }
+
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: u64,
+        arbiter: Pubkey,
+        players: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_tournament::handler(ctx, tournament_id, arbiter, players)
+    }
+
+    pub fn fund_prize_pool(ctx: Context<FundPrizePool>, amount: u64) -> Result<()> {
+        instructions::fund_prize_pool::handler(ctx, amount)
+    }
+
+    pub fn register_match(ctx: Context<RegisterMatch>, match_index: u8) -> Result<()> {
+        instructions::register_match::handler(ctx, match_index)
+    }
+
+    pub fn resolve_champion_market(ctx: Context<ResolveChampionMarket>) -> Result<()> {
+        instructions::resolve_champion_market::handler(ctx)
+    }
+
+    pub fn claim_tournament_prize(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+        instructions::claim_tournament_prize::handler(ctx)
     }
 }
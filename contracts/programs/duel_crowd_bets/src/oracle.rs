@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::errors::BetError;
+
+// Leitura de price accounts do Pyth (layout v2, o mesmo do pyth-sdk-solana).
+// O layout é lido direto dos bytes para não puxar a dependência do SDK; só os
+// campos usados na resolução são decodificados.
+
+// Programa oráculo do Pyth, dono dos price accounts
+pub mod pyth_mainnet {
+    anchor_lang::declare_id!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+}
+
+pub mod pyth_devnet {
+    anchor_lang::declare_id!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s");
+}
+
+// O build de mainnet (`--features mainnet`) não aceita o owner de devnet
+#[cfg(feature = "mainnet")]
+pub const PYTH_PROGRAM_IDS: &[Pubkey] = &[pyth_mainnet::ID];
+
+#[cfg(not(feature = "mainnet"))]
+pub const PYTH_PROGRAM_IDS: &[Pubkey] = &[pyth_mainnet::ID, pyth_devnet::ID];
+
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_VERSION: u32 = 2;
+const PYTH_PRICE_ACCOUNT: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+// Offsets dentro do PriceAccount
+const OFFSET_MAGIC: usize = 0;
+const OFFSET_VERSION: usize = 4;
+const OFFSET_ACCOUNT_TYPE: usize = 8;
+const OFFSET_EXPO: usize = 20;
+const OFFSET_TIMESTAMP: usize = 96;
+const OFFSET_AGG_PRICE: usize = 208;
+const OFFSET_AGG_CONF: usize = 216;
+const OFFSET_AGG_STATUS: usize = 224;
+const PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+/// Preço agregado do feed: `price * 10^expo`, com intervalo de confiança `conf`.
+pub struct PythPrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+    pub trading: bool,
+}
+
+impl PythPrice {
+    /// Confiança relativa ao preço, em basis points.
+    pub fn confidence_bps(&self) -> Option<u64> {
+        let price = self.price.unsigned_abs();
+        if price == 0 {
+            return None;
+        }
+        (self.conf as u128)
+            .checked_mul(10_000)
+            .map(|v| v / price as u128)
+            .and_then(|v| u64::try_from(v).ok())
+    }
+}
+
+pub fn load_pyth_price(account: &AccountInfo) -> Result<PythPrice> {
+    // Qualquer um cria uma conta com esse layout; só vale a do programa do Pyth
+    require!(
+        PYTH_PROGRAM_IDS.contains(account.owner),
+        BetError::InvalidPriceFeed
+    );
+
+    let data = account.try_borrow_data()?;
+    require!(data.len() >= PRICE_ACCOUNT_MIN_LEN, BetError::InvalidPriceFeed);
+
+    let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+    require!(
+        read_u32(OFFSET_MAGIC) == PYTH_MAGIC
+            && read_u32(OFFSET_VERSION) == PYTH_VERSION
+            && read_u32(OFFSET_ACCOUNT_TYPE) == PYTH_PRICE_ACCOUNT,
+        BetError::InvalidPriceFeed
+    );
+
+    Ok(PythPrice {
+        price: read_u64(OFFSET_AGG_PRICE) as i64,
+        conf: read_u64(OFFSET_AGG_CONF),
+        expo: read_u32(OFFSET_EXPO) as i32,
+        publish_time: read_u64(OFFSET_TIMESTAMP) as i64,
+        trading: read_u32(OFFSET_AGG_STATUS) == PYTH_STATUS_TRADING,
+    })
+}
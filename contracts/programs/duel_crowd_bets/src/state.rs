@@ -1,10 +1,20 @@
 use anchor_lang::prelude::*;
+use crate::errors::BetError;
+
+pub const MAX_OUTCOMES: usize = 8;
+pub const MAX_ALLOWLIST: usize = 8;
+pub const MAX_TEAM_SIZE: usize = 5;
+pub const MAX_SERIES_LENGTH: u8 = 9;
+pub const MAX_COMMITTEE: usize = 7;
+pub const MAX_BRACKET_SIZE: usize = MAX_OUTCOMES; // mercado do campeão tem 1 outcome por jogador
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum BetStatus {
     Open,
     Resolved,
     Cancelled,
+    Expired,
+    Draw,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -13,26 +23,127 @@ pub enum Side {
     B,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisputeStatus {
+    None,        // sem disputa (ainda)
+    Open,        // bond postado, aguardando o árbitro de fallback
+    Upheld,      // resultado mantido; bond confiscado
+    Overturned,  // resultado revertido; bond devolvido
+    Lapsed,      // fallback não decidiu a tempo; resultado mantido e bond devolvido
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PriceComparison {
+    Above, // A vence se o preço fechar acima do strike
+    Below, // A vence se o preço fechar abaixo do strike
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadRecipient {
+    CreatorA,
+    CreatorB,
+    Arbiter,
+    Protocol,
+}
+
+/// Termos de `create_bet`: stakes, prazos, fees e exigências de resolução.
+/// As identidades (`user_a`, `user_b`, `arbiter`, `bet_id`) seguem como
+/// argumentos próprios porque entram nas seeds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CreateBetParams {
+    pub stake_a: u64,
+    pub stake_b: u64,
+    pub outcome_count: u8,
+    pub deadline_duel: i64,
+    pub deadline_crowd: i64,
+    pub resolve_ts: i64,
+    pub resolve_deadline: i64,
+    pub spread_bps: u16,
+    pub creator_share_bps: u16,
+    pub arbiter_share_bps: u16,
+    pub protocol_share_bps: u16,
+    pub allowlist: Vec<Pubkey>,
+    pub arbiter_requirement: Option<ArbiterRequirement>,
+}
+
+/// Exigência opcional de `create_bet` sobre o histórico do árbitro.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ArbiterRequirement {
+    pub min_resolved: u64,        // mínimo de bets que passaram pelo árbitro
+    pub min_on_time_bps: u16,     // fração mínima resolvida dentro do prazo
+    pub max_overturned_bps: u16,  // fração máxima dos resultados revertida em disputa
+}
+
+/// Entrada de `set_teams`: membro do roster e quanto ele precisa depositar.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RosterEntry {
+    pub member: Pubkey,
+    pub stake: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TeamMember {
+    pub member: Pubkey,
+    pub stake: u64,              // quanto precisa depositar
+    pub principal: u64,          // quanto realmente entrou (descontado transfer fee)
+    pub deposited: bool,
+    pub principal_claimed: bool, // já sacou prêmio ou reembolso do stake
+    pub spread_claimed: bool,    // já sacou sua parte do spread dos criadores
+}
+
+impl TeamMember {
+    pub const LEN: usize = 32 + 8 + 8 + 1 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CommitteeMember {
+    pub member: Pubkey,
+    pub voted: bool,
+    pub vote: Option<u8>,        // outcome votado; None = empate
+    pub fee_claimed: bool,       // já sacou sua parte do spread do árbitro
+}
+
+impl CommitteeMember {
+    pub const LEN: usize = 32 + 1 + (1 + 1) + 1;
+}
+
 #[account]
 pub struct Bet {
     // Identidades principais
     pub user_a: Pubkey,
-    pub user_b: Pubkey,
+    pub user_b: Pubkey,         // Pubkey::default() = desafio aberto, ainda sem oponente
+    pub allowlist: Vec<Pubkey>, // desafio aberto: quem pode aceitar (vazia = qualquer um)
     pub arbiter: Pubkey,
+    pub bet_id: u64,            // nonce nas seeds: o mesmo user_a pode ter vários duelos
 
-    // Stake do duelo principal
-    pub stake_lamports: u64,
+    // Moeda do bet: Pubkey::default() = SOL nativo, senão mint SPL guardado no vault
+    pub mint: Pubkey,
+
+    // Stake do duelo principal (em lamports ou unidades do mint); pode ser
+    // assimétrico, ex.: favorito arrisca 3x o que arrisca o azarão
+    pub stake_a: u64,
+    pub stake_b: u64,
     pub user_a_deposited: bool,
     pub user_b_deposited: bool,
+    pub principal_a: u64,       // quanto do stake de A realmente entrou (descontado transfer fee)
+    pub principal_b: u64,       // idem para B
+
+    // Duelo em equipe: rosters de cada lado (vazios = duelo 1v1 entre user_a e user_b).
+    // Com rosters, stake/principal/deposited de cada lado são os agregados do time.
+    pub team_a: Vec<TeamMember>,
+    pub team_b: Vec<TeamMember>,
 
     // Tempo
     pub deadline_duel: i64,     // até quando A e B podem depositar
     pub deadline_crowd: i64,    // até quando torcida pode entrar
     pub resolve_ts: i64,        // a partir de quando o árbitro pode resolver
+    pub resolve_deadline: i64,  // até quando o árbitro pode resolver; depois disso, reembolso
 
-    // Pools da torcida (valores líquidos, após fee)
-    pub net_support_a: u64,     // soma de net apostado no lado A
-    pub net_support_b: u64,     // soma de net apostado no lado B
+    // Pools da torcida (valores líquidos, após fee), um por resultado possível.
+    // Resultado 0 = vitória de A, 1 = vitória de B, 2+ = outros resultados
+    // (ex.: empate, terceiro competidor), nos quais os duelistas recuperam o stake.
+    pub outcome_count: u8,
+    pub net_support: [u64; MAX_OUTCOMES],
 
     // Pools de fee (spread) acumulado
     pub spread_pool_creators: u64,   // fee destinado a A+B
@@ -47,10 +158,53 @@ pub struct Bet {
 
     // Status
     pub status: BetStatus,
-    pub winner_side: Option<Side>,
+    pub winner_side: Option<Side>,    // duelo: None se o resultado não é vitória de A/B
+    pub winning_outcome: Option<u8>,  // torcida: None com status Draw = empate (push)
+
+    // Série melhor-de-N (0 = jogo único, resolvido por declare_winner)
+    pub series_length: u8,
+    pub wins_a: u8,
+    pub wins_b: u8,
+
+    // Disputa (dispute_window = 0: resultado final assim que declarado)
+    pub fallback_arbiter: Pubkey,    // decide disputas abertas contra o resultado
+    pub dispute_window: i64,         // segundos após a resolução em que cabe disputa
+    pub dispute_bond: u64,           // bond exigido para abrir disputa
+    pub dispute_ends_at: i64,        // fim da janela, definido na resolução
+    pub disputer: Pubkey,
+    pub dispute_bond_posted: u64,    // quanto do bond realmente entrou
+    pub dispute_status: DisputeStatus,
+
+    // Comitê de árbitros (vazio = `arbiter` resolve sozinho via declare_winner)
+    pub committee: Vec<CommitteeMember>,
+    pub committee_threshold: u8,     // votos iguais necessários para resolver
+
+    // Resolução por oráculo Pyth (oracle_feed = default: resolução pelo árbitro)
+    pub oracle_feed: Pubkey,         // price account lido por resolve_with_oracle
+    pub strike_price: i64,           // strike * 10^strike_expo, no expoente do feed
+    pub strike_expo: i32,
+    pub price_comparison: PriceComparison,
+    pub max_price_age: i64,          // preço aceito se publicado até resolve_ts + max_price_age
+    pub max_confidence_bps: u16,     // conf máximo em relação ao preço
+
+    // Resolução por atestação Ed25519 (attestor = default: sem atestador)
+    pub attestor: Pubkey,            // chave que assina (bet, winner_side, timestamp)
+
+    // Bond do árbitro, em SOL, guardado no PDA ArbiterBond (0 = sem bond)
+    pub arbiter_bond: u64,
+    pub arbiter_bond_posted: bool,
+    pub arbiter_bond_settled: bool,  // já devolvido ou confiscado
+
+    // Liquidação: bitfield de pagamentos já realizados (CLAIM_*)
+    pub settlement_claims: u8,
+    pub open_positions: u32,         // SupportPositions ainda não sacadas/reembolsadas
 
     // Metadados
     pub protocol_treasury: Pubkey,
+    pub payer: Pubkey,               // quem pagou o rent do Bet; recebe de volta em close_bet
+    pub tournament: Pubkey,          // Pubkey::default() = bet avulso
+    pub arbiter_profile: Pubkey,     // ArbiterProfile atualizado por este bet; default = sem histórico
+    pub match_index: u8,             // posição no bracket (ou Tournament::CHAMPION_MARKET)
 
     pub bump: u8,
 }
@@ -59,15 +213,24 @@ impl Bet {
     pub const LEN: usize = 8 + // discriminator
         32 + // user_a
         32 + // user_b
+        4 + 32 * MAX_ALLOWLIST + // allowlist
         32 + // arbiter
-        8 +  // stake_lamports
+        8 +  // bet_id
+        32 + // mint
+        8 +  // stake_a
+        8 +  // stake_b
         1 +  // user_a_deposited
         1 +  // user_b_deposited
+        8 +  // principal_a
+        8 +  // principal_b
+        4 + TeamMember::LEN * MAX_TEAM_SIZE + // team_a
+        4 + TeamMember::LEN * MAX_TEAM_SIZE + // team_b
         8 +  // deadline_duel
         8 +  // deadline_crowd
         8 +  // resolve_ts
-        8 +  // net_support_a
-        8 +  // net_support_b
+        8 +  // resolve_deadline
+        1 +  // outcome_count
+        8 * MAX_OUTCOMES + // net_support
         8 +  // spread_pool_creators
         8 +  // spread_pool_arbiter
         8 +  // spread_pool_protocol
@@ -77,7 +240,427 @@ impl Bet {
         2 +  // protocol_share_bps
         1 +  // status enum
         1 + 1 + // winner_side (Option<Side>)
+        1 + 1 + // winning_outcome (Option<u8>)
+        1 +  // series_length
+        1 +  // wins_a
+        1 +  // wins_b
+        32 + // fallback_arbiter
+        8 +  // dispute_window
+        8 +  // dispute_bond
+        8 +  // dispute_ends_at
+        32 + // disputer
+        8 +  // dispute_bond_posted
+        1 +  // dispute_status
+        4 + CommitteeMember::LEN * MAX_COMMITTEE + // committee
+        1 +  // committee_threshold
+        32 + // oracle_feed
+        8 +  // strike_price
+        4 +  // strike_expo
+        1 +  // price_comparison
+        8 +  // max_price_age
+        2 +  // max_confidence_bps
+        32 + // attestor
+        8 +  // arbiter_bond
+        1 +  // arbiter_bond_posted
+        1 +  // arbiter_bond_settled
+        1 +  // settlement_claims
+        4 +  // open_positions
         32 + // protocol_treasury
+        32 + // payer
+        32 + // tournament
+        32 + // arbiter_profile
+        1 +  // match_index
+        1;   // bump
+
+    // Bits de `settlement_claims`, um por categoria de pagamento
+    pub const CLAIM_PRINCIPAL_A: u8 = 1 << 0;
+    pub const CLAIM_PRINCIPAL_B: u8 = 1 << 1;
+    pub const CLAIM_SPREAD_A: u8 = 1 << 2;
+    pub const CLAIM_SPREAD_B: u8 = 1 << 3;
+    pub const CLAIM_SPREAD_ARBITER: u8 = 1 << 4;
+    pub const CLAIM_SPREAD_PROTOCOL: u8 = 1 << 5;
+
+    pub fn principal_claim_flag(side: Side) -> u8 {
+        match side {
+            Side::A => Self::CLAIM_PRINCIPAL_A,
+            Side::B => Self::CLAIM_PRINCIPAL_B,
+        }
+    }
+
+    pub fn spread_claim_flag(share: SpreadRecipient) -> u8 {
+        match share {
+            SpreadRecipient::CreatorA => Self::CLAIM_SPREAD_A,
+            SpreadRecipient::CreatorB => Self::CLAIM_SPREAD_B,
+            SpreadRecipient::Arbiter => Self::CLAIM_SPREAD_ARBITER,
+            SpreadRecipient::Protocol => Self::CLAIM_SPREAD_PROTOCOL,
+        }
+    }
+
+    /// Resultado 0/1 é vitória de A/B; os demais não têm vencedor no duelo.
+    pub fn side_for_outcome(outcome: u8) -> Option<Side> {
+        match outcome {
+            0 => Some(Side::A),
+            1 => Some(Side::B),
+            _ => None,
+        }
+    }
+
+    /// Cada duelista recupera o próprio stake em vez de haver um vencedor.
+    pub fn returns_stakes(&self) -> bool {
+        match self.status {
+            BetStatus::Expired | BetStatus::Draw => true,
+            BetStatus::Resolved => self.winner_side.is_none(),
+            _ => false,
+        }
+    }
+
+    /// Torcida recebe de volta o valor líquido: empate, ou ninguém apoiou o
+    /// outcome vencedor (senão o pool inteiro sobraria no vault).
+    pub fn refunds_crowd(&self) -> bool {
+        match (self.status, self.winning_outcome) {
+            (BetStatus::Draw, _) => true,
+            (BetStatus::Resolved, Some(outcome)) => self.net_support[outcome as usize] == 0,
+            _ => false,
+        }
+    }
+
+    pub fn total_crowd_pool(&self) -> Option<u64> {
+        self.net_support[..self.outcome_count as usize]
+            .iter()
+            .try_fold(0u64, |acc, &v| acc.checked_add(v))
+    }
+
+    /// Vitórias necessárias para fechar a série (maioria de `series_length`).
+    pub fn series_wins_needed(&self) -> u8 {
+        self.series_length / 2 + 1
+    }
+
+    pub fn in_tournament(&self) -> bool {
+        self.tournament != Pubkey::default()
+    }
+
+    pub fn is_champion_market(&self) -> bool {
+        self.in_tournament() && self.match_index == Tournament::CHAMPION_MARKET
+    }
+
+    pub fn is_open_challenge(&self) -> bool {
+        self.user_b == Pubkey::default()
+    }
+
+    pub fn has_teams(&self) -> bool {
+        !self.team_a.is_empty()
+    }
+
+    pub fn team(&self, side: Side) -> &[TeamMember] {
+        match side {
+            Side::A => &self.team_a,
+            Side::B => &self.team_b,
+        }
+    }
+
+    pub fn team_mut(&mut self, side: Side) -> &mut Vec<TeamMember> {
+        match side {
+            Side::A => &mut self.team_a,
+            Side::B => &mut self.team_b,
+        }
+    }
+
+    /// Lado e posição do membro no roster.
+    pub fn find_member(&self, key: Pubkey) -> Option<(Side, usize)> {
+        [Side::A, Side::B].into_iter().find_map(|side| {
+            self.team(side)
+                .iter()
+                .position(|m| m.member == key)
+                .map(|index| (side, index))
+        })
+    }
+
+    /// Duelistas e membros dos rosters: quem tem stake no resultado.
+    pub fn is_duelist(&self, key: Pubkey) -> bool {
+        key == self.user_a || key == self.user_b || self.find_member(key).is_some()
+    }
+
+    pub fn is_deposited(&self, side: Side) -> bool {
+        match side {
+            Side::A => self.user_a_deposited,
+            Side::B => self.user_b_deposited,
+        }
+    }
+
+    /// Todo stake depositado desse lado já foi pago (prêmio ou reembolso).
+    pub fn principal_returned(&self, side: Side) -> bool {
+        if self.has_teams() {
+            self.team(side)
+                .iter()
+                .all(|m| !m.deposited || m.principal_claimed)
+        } else {
+            !self.is_deposited(side) || self.is_claimed(Self::principal_claim_flag(side))
+        }
+    }
+
+    /// Marca a devolução do stake de `participant` (duelista ou membro de
+    /// roster) e retorna quanto deve ser pago.
+    pub fn take_stake_refund(&mut self, participant: Pubkey) -> Result<u64> {
+        if self.has_teams() {
+            let (side, index) = self
+                .find_member(participant)
+                .ok_or(BetError::InvalidParticipant)?;
+            let member = &mut self.team_mut(side)[index];
+            require!(member.deposited, BetError::NothingToRefund);
+            require!(!member.principal_claimed, BetError::PrincipalAlreadyClaimed);
+            member.principal_claimed = true;
+            let amount = member.principal;
+
+            // O bit do lado só é marcado quando o time inteiro foi reembolsado
+            if self.principal_returned(side) {
+                self.settlement_claims |= Self::principal_claim_flag(side);
+            }
+            return Ok(amount);
+        }
+
+        let side = if participant == self.user_a {
+            Side::A
+        } else if participant == self.user_b {
+            Side::B
+        } else {
+            return err!(BetError::InvalidParticipant);
+        };
+        require!(self.is_deposited(side), BetError::NothingToRefund);
+
+        let claim_flag = Self::principal_claim_flag(side);
+        require!(!self.is_claimed(claim_flag), BetError::PrincipalAlreadyClaimed);
+        self.settlement_claims |= claim_flag;
+
+        Ok(self.principal_of(side))
+    }
+
+    pub fn principal_of(&self, side: Side) -> u64 {
+        match side {
+            Side::A => self.principal_a,
+            Side::B => self.principal_b,
+        }
+    }
+
+    pub fn is_token_bet(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+
+    pub fn is_claimed(&self, flag: u8) -> bool {
+        self.settlement_claims & flag != 0
+    }
+
+    /// Em duelos de equipe, a fatia dos criadores é dividida igualmente entre
+    /// todos os membros dos dois rosters.
+    pub fn creator_spread_per_member(&self) -> u64 {
+        let members = (self.team_a.len() + self.team_b.len()) as u64;
+        self.spread_pool_creators.checked_div(members).unwrap_or(0)
+    }
+
+    pub fn has_committee(&self) -> bool {
+        !self.committee.is_empty()
+    }
+
+    pub fn has_oracle(&self) -> bool {
+        self.oracle_feed != Pubkey::default()
+    }
+
+    pub fn has_attestor(&self) -> bool {
+        self.attestor != Pubkey::default()
+    }
+
+    /// O resultado depende do `arbiter` (e não de comitê, oráculo ou atestador).
+    pub fn resolved_by_arbiter(&self) -> bool {
+        !self.has_committee() && !self.has_oracle() && !self.has_attestor()
+    }
+
+    /// Perfil do árbitro que este bet precisa atualizar, se rastreado.
+    pub fn tracked_profile<'a, 'info>(
+        &self,
+        profile: Option<&'a mut Account<'info, ArbiterProfile>>,
+    ) -> Result<Option<&'a mut Account<'info, ArbiterProfile>>> {
+        if self.arbiter_profile == Pubkey::default() || !self.resolved_by_arbiter() {
+            return Ok(None);
+        }
+        let profile = profile.ok_or(BetError::ArbiterProfileRequired)?;
+        require!(
+            profile.key() == self.arbiter_profile,
+            BetError::InvalidArbiterProfile
+        );
+        Ok(Some(profile))
+    }
+
+    /// Membros do comitê que votaram no resultado final.
+    pub fn majority_voters(&self) -> u64 {
+        self.committee
+            .iter()
+            .filter(|m| m.voted && m.vote == self.winning_outcome)
+            .count() as u64
+    }
+
+    /// Com comitê, a fatia do árbitro é dividida entre quem votou com a maioria.
+    pub fn arbiter_fee_per_voter(&self) -> u64 {
+        self.spread_pool_arbiter
+            .checked_div(self.majority_voters())
+            .unwrap_or(0)
+    }
+
+    pub fn spread_share_amount(&self, share: SpreadRecipient) -> u64 {
+        if self.has_committee() && share == SpreadRecipient::Arbiter {
+            return self.arbiter_fee_per_voter() * self.majority_voters();
+        }
+
+        if self.has_teams() {
+            let per_member = self.creator_spread_per_member();
+            match share {
+                SpreadRecipient::CreatorA => return per_member * self.team_a.len() as u64,
+                SpreadRecipient::CreatorB => return per_member * self.team_b.len() as u64,
+                _ => {}
+            }
+        }
+
+        let fee_a = self.spread_pool_creators / 2;
+        match share {
+            SpreadRecipient::CreatorA => fee_a,
+            SpreadRecipient::CreatorB => self.spread_pool_creators - fee_a,
+            SpreadRecipient::Arbiter => self.spread_pool_arbiter,
+            SpreadRecipient::Protocol => self.spread_pool_protocol,
+        }
+    }
+
+    /// O resultado não pode mais mudar: sem janela de disputa, janela encerrada
+    /// sem disputa, ou disputa já decidida.
+    pub fn is_result_final(&self, now: i64) -> bool {
+        match self.dispute_status {
+            DisputeStatus::Open => false,
+            DisputeStatus::None => self.dispute_window == 0 || now >= self.dispute_ends_at,
+            DisputeStatus::Upheld | DisputeStatus::Overturned | DisputeStatus::Lapsed => true,
+        }
+    }
+
+    /// Até quando o árbitro de fallback pode decidir uma disputa aberta.
+    pub fn fallback_deadline(&self) -> Option<i64> {
+        self.dispute_ends_at.checked_add(self.dispute_window)
+    }
+
+    /// A partir de quando os duelistas podem trocar sozinhos um árbitro que não
+    /// resolveu: metade da janela de resolução, deixando a outra metade ao novo.
+    pub fn arbiter_timeout(&self) -> i64 {
+        ((self.resolve_ts as i128 + self.resolve_deadline as i128) / 2) as i64
+    }
+
+    /// Todos os pagamentos do Bet foram feitos: torcida, principal e spread.
+    pub fn is_settled(&self) -> bool {
+        if self.open_positions > 0 || self.dispute_status == DisputeStatus::Open {
+            return false;
+        }
+        if self.arbiter_bond_posted && !self.arbiter_bond_settled {
+            return false;
+        }
+
+        let principal_settled = match (self.status, self.winner_side) {
+            (BetStatus::Open, _) => return false,
+            (BetStatus::Resolved, Some(side)) => self.principal_returned(side),
+            // Cada duelista que depositou precisa ter recuperado o próprio stake
+            _ => self.principal_returned(Side::A) && self.principal_returned(Side::B),
+        };
+
+        // Fatias zeradas não precisam ser sacadas
+        let spread_settled = [
+            SpreadRecipient::CreatorA,
+            SpreadRecipient::CreatorB,
+            SpreadRecipient::Arbiter,
+            SpreadRecipient::Protocol,
+        ]
+        .iter()
+        .all(|&share| {
+            self.spread_share_amount(share) == 0
+                || self.is_claimed(Self::spread_claim_flag(share))
+        });
+
+        principal_settled && spread_settled
+    }
+}
+
+/// Histórico público de um árbitro, atualizado pelos bets que o rastreiam.
+#[account]
+pub struct ArbiterProfile {
+    pub arbiter: Pubkey,
+    pub resolved_count: u64,    // bets que passaram pela janela de resolução: declarados ou expirados
+    pub on_time_count: u64,     // declarados pelo árbitro antes de resolve_deadline
+    pub overturned_count: u64,  // resultados revertidos em disputa
+    pub fees_earned: u64,       // fee de árbitro recebido em bets de SOL (lamports)
+    pub bump: u8,
+}
+
+impl ArbiterProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // arbiter
+        8 +  // resolved_count
+        8 +  // on_time_count
+        8 +  // overturned_count
+        8 +  // fees_earned
+        1;   // bump
+
+    pub fn record_resolution(&mut self, on_time: bool) -> Result<()> {
+        self.resolved_count = self.resolved_count
+            .checked_add(1)
+            .ok_or(BetError::ArithmeticOverflow)?;
+        if on_time {
+            self.on_time_count = self.on_time_count
+                .checked_add(1)
+                .ok_or(BetError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn record_overturn(&mut self) -> Result<()> {
+        self.overturned_count = self.overturned_count
+            .checked_add(1)
+            .ok_or(BetError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_fee(&mut self, bet: &Bet, amount: u64) -> Result<()> {
+        if !bet.is_token_bet() {
+            self.fees_earned = self.fees_earned
+                .checked_add(amount)
+                .ok_or(BetError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    fn ratio_bps(count: u64, total: u64) -> u64 {
+        (count as u128 * 10_000)
+            .checked_div(total as u128)
+            .map(|v| v as u64)
+            .unwrap_or(0)
+    }
+
+    /// As duas taxas usam `resolved_count` como base: reversões contam sobre
+    /// todos os bets resolvidos, não só os declarados no prazo.
+    pub fn meets(&self, requirement: &ArbiterRequirement) -> bool {
+        self.resolved_count >= requirement.min_resolved
+            && Self::ratio_bps(self.on_time_count, self.resolved_count)
+                >= requirement.min_on_time_bps as u64
+            && Self::ratio_bps(self.overturned_count, self.resolved_count)
+                <= requirement.max_overturned_bps as u64
+    }
+}
+
+/// Escrow do bond do árbitro: lamports guardados num PDA por Bet.
+#[account]
+pub struct ArbiterBond {
+    pub bet: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl ArbiterBond {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // bet
+        32 + // arbiter
+        8 +  // amount
         1;   // bump
 }
 
@@ -85,9 +668,10 @@ impl Bet {
 pub struct SupportPosition {
     pub bet: Pubkey,        // referência ao Bet
     pub bettor: Pubkey,     // usuário que apostou
-    pub side: Side,         // A ou B
+    pub outcome: u8,        // índice do resultado apostado
 
     pub net_amount: u64,    // valor líquido (após fee) apostado por ele
+    pub gross_amount: u64,  // valor bruto (com fee), devolvido em caso de cancelamento
     pub claimed: bool,      // se já sacou ou não
 
     pub bump: u8,
@@ -97,8 +681,114 @@ impl SupportPosition {
     pub const LEN: usize = 8 + // discriminator
         32 + // bet
         32 + // bettor
-        1 +  // side
+        1 +  // outcome
         8 +  // net_amount
+        8 +  // gross_amount
         1 +  // claimed
         1;   // bump
 }
+
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,                 // única chave que pode alterar a config
+    pub treasury: Pubkey,              // treasury canônico; todo Bet usa este endereço
+
+    // Limites aplicados em create_bet (basis points)
+    pub min_spread_bps: u16,
+    pub max_spread_bps: u16,
+    pub min_protocol_share_bps: u16,
+
+    // Emergência: bloqueia entrada de dinheiro novo; saques continuam abertos
+    pub paused: bool,
+
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        32 + // treasury
+        2 +  // min_spread_bps
+        2 +  // max_spread_bps
+        2 +  // min_protocol_share_bps
+        1 +  // paused
+        1;   // bump
+
+    pub fn validate_limits(
+        min_spread_bps: u16,
+        max_spread_bps: u16,
+        min_protocol_share_bps: u16,
+    ) -> bool {
+        min_spread_bps > 0
+            && min_spread_bps <= max_spread_bps
+            && max_spread_bps <= 10_000
+            && min_protocol_share_bps <= 10_000
+    }
+}
+
+/// Bracket de eliminação simples. Cada match é um Bet registrado via
+/// `register_match`; os matches são indexados rodada a rodada (primeira rodada
+/// em `0..n/2`, final em `n - 2`) e o vencedor do match `i` joga o match
+/// `n/2 + i/2`.
+#[account]
+pub struct Tournament {
+    pub organizer: Pubkey,
+    pub tournament_id: u64,
+    pub arbiter: Pubkey,            // árbitro exigido em todos os matches
+
+    pub players: Vec<Pubkey>,       // ordem de seed da primeira rodada
+    pub matches: Vec<Pubkey>,       // Bet de cada match; default = ainda não registrado
+    pub advancing: Vec<Pubkey>,     // vencedor de cada match; default = pendente
+    pub champion: Option<Pubkey>,
+    pub champion_market: Pubkey,    // Bet da torcida no campeão; default = nenhum
+
+    // Prêmio em SOL guardado no próprio PDA do torneio
+    pub prize_pool: u64,
+    pub prize_claimed: bool,
+
+    pub bump: u8,
+}
+
+impl Tournament {
+    pub const CHAMPION_MARKET: u8 = u8::MAX;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // organizer
+        8 +  // tournament_id
+        32 + // arbiter
+        4 + 32 * MAX_BRACKET_SIZE +       // players
+        4 + 32 * (MAX_BRACKET_SIZE - 1) + // matches
+        4 + 32 * (MAX_BRACKET_SIZE - 1) + // advancing
+        1 + 32 + // champion
+        32 + // champion_market
+        8 +  // prize_pool
+        1 +  // prize_claimed
+        1;   // bump
+
+    pub fn final_match(&self) -> usize {
+        self.players.len() - 2
+    }
+
+    /// Match da rodada seguinte que recebe o vencedor de `index`.
+    pub fn next_match(&self, index: usize) -> Option<usize> {
+        if index >= self.final_match() {
+            return None;
+        }
+        Some(self.players.len() / 2 + index / 2)
+    }
+
+    /// Quem joga o match `index` (A, B); `None` enquanto a rodada anterior não terminou.
+    pub fn match_players(&self, index: usize) -> Option<(Pubkey, Pubkey)> {
+        let first_round = self.players.len() / 2;
+        let (a, b) = if index < first_round {
+            (self.players[2 * index], self.players[2 * index + 1])
+        } else {
+            let child = 2 * (index - first_round);
+            (self.advancing[child], self.advancing[child + 1])
+        };
+        if a == Pubkey::default() || b == Pubkey::default() {
+            return None;
+        }
+        Some((a, b))
+    }
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::state::Bet;
+use crate::errors::BetError;
+
+// Movimentação de valor entre usuários e o Bet.
+// Bets nativos guardam lamports no próprio PDA; bets em token (SPL Token ou
+// Token-2022) guardam os tokens no vault (token account PDA com authority = Bet).
+
+/// Retorna o valor que de fato entrou no Bet. Para mints Token-2022 com
+/// transfer fee isso é menor que `amount`, e é esse valor que deve ser creditado.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_to_bet<'info>(
+    bet: &Account<'info, Bet>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    from: &Signer<'info>,
+    from_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+) -> Result<u64> {
+    if !bet.is_token_bet() {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: from.to_account_info(),
+                    to: bet.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        return Ok(amount);
+    }
+
+    let (mint, vault, token_program, from_token) = match (mint, vault, token_program, from_token) {
+        (Some(mint), Some(vault), Some(token_program), Some(from_token)) => {
+            (mint, vault, token_program, from_token)
+        }
+        _ => return err!(BetError::MissingTokenAccounts),
+    };
+    require!(mint.key() == bet.mint, BetError::InvalidTokenAccount);
+    require!(from_token.mint == bet.mint, BetError::InvalidTokenAccount);
+
+    let balance_before = vault.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from_token.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: from.to_account_info(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    let balance_after = token_balance(&vault.to_account_info())?;
+    balance_after
+        .checked_sub(balance_before)
+        .ok_or_else(|| error!(BetError::ArithmeticOverflow))
+}
+
+pub fn pay_from_bet<'info>(
+    bet: &Account<'info, Bet>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    vault: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    to: &AccountInfo<'info>,
+    to_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if !bet.is_token_bet() {
+        **bet.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? += amount;
+        return Ok(());
+    }
+
+    let (mint, vault, token_program, to_token) = match (mint, vault, token_program, to_token) {
+        (Some(mint), Some(vault), Some(token_program), Some(to_token)) => {
+            (mint, vault, token_program, to_token)
+        }
+        _ => return err!(BetError::MissingTokenAccounts),
+    };
+    require!(mint.key() == bet.mint, BetError::InvalidTokenAccount);
+    // O token account de destino precisa pertencer a quem tem direito ao pagamento
+    require!(to_token.owner == to.key(), BetError::InvalidTokenAccount);
+    require!(to_token.mint == bet.mint, BetError::InvalidTokenAccount);
+
+    with_bet_signer(bet, |signer| {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to_token.to_account_info(),
+                    authority: bet.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+            mint.decimals,
+        )
+    })
+}
+
+/// Fecha o vault de um bet de token, devolvendo o rent para `destination`.
+/// O saldo precisa ter sido zerado antes; em mints com transfer fee, as taxas
+/// retidas no vault precisam ser colhidas (harvest) para o mint antes do close.
+pub fn close_vault<'info>(
+    bet: &Account<'info, Bet>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    with_bet_signer(bet, |signer| {
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: vault.to_account_info(),
+                destination: destination.clone(),
+                authority: bet.to_account_info(),
+            },
+            signer,
+        ))
+    })
+}
+
+/// Saldo atual do token account, relido após um CPI.
+pub fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    let token_account = TokenAccount::try_deserialize(&mut &data[..])?;
+    Ok(token_account.amount)
+}
+
+fn with_bet_signer<T>(
+    bet: &Account<Bet>,
+    f: impl FnOnce(&[&[&[u8]]]) -> Result<T>,
+) -> Result<T> {
+    let bet_id = bet.bet_id.to_le_bytes();
+    let seeds = &[
+        b"bet".as_ref(),
+        bet.user_a.as_ref(),
+        bet_id.as_ref(),
+        &[bet.bump],
+    ];
+    f(&[&seeds[..]])
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+// Só para testes no localnet: carregado no endereço do Pyth de devnet, deixa os
+// testes escreverem price accounts com o layout v2 e publish_time atual.
+declare_id!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s");
+
+#[program]
+pub mod mock_pyth {
+    use super::*;
+
+    /// Copia `data` para a conta a partir de `offset`. A conta é criada antes
+    /// pelo teste (System Program) com este programa como owner.
+    pub fn write(ctx: Context<Write>, offset: u64, data: Vec<u8>) -> Result<()> {
+        let mut account_data = ctx.accounts.price.try_borrow_mut_data()?;
+        let start = offset as usize;
+        let end = start
+            .checked_add(data.len())
+            .filter(|&end| end <= account_data.len())
+            .ok_or(ProgramError::AccountDataTooSmall)?;
+        account_data[start..end].copy_from_slice(&data);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Write<'info> {
+    /// CHECK: Conta crua no layout do Pyth; só o owner (este programa) pode escrever
+    #[account(mut)]
+    pub price: UncheckedAccount<'info>,
+}
@@ -10,6 +10,8 @@
  * - ARBITER: base58 do árbitro (opcional; padrão: própria wallet)
  * - STAKE_SOL: valor em SOL para cada jogador (padrão: 1.0)
  * - DUEL_HOURS / CROWD_HOURS / RESOLVE_HOURS: horas até cada deadline (padrão: 1/2/3)
+ * - RESOLVE_DEADLINE_HOURS: horas até o bet poder ser expirado (padrão: RESOLVE_HOURS + 24)
+ * - BET_ID: nonce do bet, único por desafiante (padrão: Date.now())
  */
 
 const fs = require("fs");
@@ -47,6 +49,10 @@ async function main() {
   const duelHours = Number(process.env.DUEL_HOURS || "1");
   const crowdHours = Number(process.env.CROWD_HOURS || "2");
   const resolveHours = Number(process.env.RESOLVE_HOURS || "3");
+  const resolveDeadlineHours = Number(
+    process.env.RESOLVE_DEADLINE_HOURS || String(resolveHours + 24)
+  );
+  const betId = new anchor.BN(process.env.BET_ID || String(Date.now()));
 
   const payerKp = loadKeypair(keypairPath);
   const wallet = new anchor.Wallet(payerKp);
@@ -66,6 +72,8 @@ async function main() {
     bet: [147, 23, 35, 59, 15, 75, 155, 32],
     SupportPosition: [202, 124, 14, 86, 154, 235, 215, 186],
     supportPosition: [202, 124, 14, 86, 154, 235, 215, 186],
+    ProtocolConfig: [207, 91, 250, 28, 152, 179, 215, 209],
+    protocolConfig: [207, 91, 250, 28, 152, 179, 215, 209],
   };
 
   const normalizeIdlTypes = (value) => {
@@ -192,30 +200,48 @@ async function main() {
   const deadlineDuel = new anchor.BN(now + duelHours * 3600);
   const deadlineCrowd = new anchor.BN(now + crowdHours * 3600);
   const resolveTs = new anchor.BN(now + resolveHours * 3600);
+  const resolveDeadline = new anchor.BN(now + resolveDeadlineHours * 3600);
 
   const [betPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("bet"), arbiterPk.toBuffer(), userA.toBuffer(), userB.toBuffer()],
+    [Buffer.from("bet"), userA.toBuffer(), betId.toArrayLike(Buffer, "le", 8)],
     PROGRAM_ID
   );
+  const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+
+  // create_bet exige o treasury definido pelo admin na config
+  const configInfo = await connection.getAccountInfo(configPda);
+  if (!configInfo) {
+    throw new Error("Protocol config not initialized; run initialize_config first.");
+  }
+  const config = coder.accounts.decode("protocolConfig", configInfo.data);
 
   console.log("RPC", rpcUrl);
   console.log("Payer", userA.toBase58());
   console.log("Opponent", userB.toBase58());
   console.log("Arbiter", arbiterPk.toBase58());
+  console.log("Bet ID", betId.toString());
   console.log("Bet PDA", betPda.toBase58());
 
   const data = coder.instruction.encode("createBet", {
     userA,
     userB,
     arbiter: arbiterPk,
-    stakeLamports,
-    deadlineDuel,
-    deadlineCrowd,
-    resolveTs,
-    spreadBps: 200,
-    creatorShareBps: 5000,
-    arbiterShareBps: 2000,
-    protocolShareBps: 3000,
+    betId,
+    params: {
+      stakeA: stakeLamports,
+      stakeB: stakeLamports,
+      outcomeCount: 2, // A ou B
+      deadlineDuel,
+      deadlineCrowd,
+      resolveTs,
+      resolveDeadline,
+      spreadBps: 200,
+      creatorShareBps: 5000,
+      arbiterShareBps: 2000,
+      protocolShareBps: 3000,
+      allowlist: [],
+      arbiterRequirement: null,
+    },
   });
 
   const ix = new anchor.web3.TransactionInstruction({
@@ -223,7 +249,15 @@ async function main() {
     keys: [
       { pubkey: userA, isSigner: true, isWritable: true },
       { pubkey: betPda, isSigner: false, isWritable: true },
-      { pubkey: userA, isSigner: false, isWritable: false }, // protocolTreasury placeholder
+      { pubkey: configPda, isSigner: false, isWritable: false },
+      { pubkey: config.treasury, isSigner: false, isWritable: false },
+      // Contas opcionais omitidas (arbiterProfile, arbiterSigner, mint, vault, tokenProgram)
+      // são passadas como o próprio program id
+      ...Array.from({ length: 5 }, () => ({
+        pubkey: PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      })),
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     data,
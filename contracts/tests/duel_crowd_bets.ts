@@ -1,6 +1,19 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { DuelCrowdBets } from "../target/types/duel_crowd_bets";
+import { MockPyth } from "../target/types/mock_pyth";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  getAccount,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("duel_crowd_bets", () => {
@@ -9,6 +22,8 @@ describe("duel_crowd_bets", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.DuelCrowdBets as Program<DuelCrowdBets>;
+  // Stands in for the Pyth program (devnet address) on the local validator
+  const mockPyth = anchor.workspace.MockPyth as Program<MockPyth>;
 
   // Test wallets
   let userA: anchor.web3.Keypair;
@@ -20,10 +35,147 @@ describe("duel_crowd_bets", () => {
 
   let betPda: anchor.web3.PublicKey;
   let betBump: number;
+  let mainResolveTs: number;
+
+  const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
 
   const stakeAmount = new anchor.BN(1_000_000_000); // 1 SOL
   const supportAmount = new anchor.BN(500_000_000); // 0.5 SOL
 
+  const deriveBetPda = (betId: anchor.BN, challenger = userA.publicKey) =>
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("bet"),
+        challenger.toBuffer(),
+        betId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+  const nowTs = () => Math.floor(Date.now() / 1000);
+
+  // Waits until the validator clock has passed `ts`
+  const sleepUntil = (ts: number) =>
+    new Promise((resolve) => setTimeout(resolve, Math.max(0, ts - nowTs() + 1) * 1000));
+
+  // Deposits close in 1h, the crowd in 2h, and the arbiter resolves between 3h and 4h
+  const defaultTerms = () => {
+    const now = nowTs();
+    return {
+      stakeA: stakeAmount,
+      stakeB: stakeAmount,
+      outcomeCount: 2, // A or B
+      deadlineDuel: new anchor.BN(now + 3600),
+      deadlineCrowd: new anchor.BN(now + 7200),
+      resolveTs: new anchor.BN(now + 10800),
+      resolveDeadline: new anchor.BN(now + 14400),
+      spreadBps: 200, // 2%
+      creatorShareBps: 5000, // 50% of the fee
+      arbiterShareBps: 2000, // 20%
+      protocolShareBps: 3000, // 30%
+      allowlist: [],
+      arbiterRequirement: null,
+    };
+  };
+
+  // For tests that resolve: deposits and crowd close within 5s, resolve_ts is 6s away
+  const quickTimeline = () => {
+    const now = nowTs();
+    return {
+      deadlineDuel: new anchor.BN(now + 4),
+      deadlineCrowd: new anchor.BN(now + 5),
+      resolveTs: new anchor.BN(now + 6),
+      resolveDeadline: new anchor.BN(now + 3600),
+    };
+  };
+
+  // userA vs userB judged by `arbiter`, with `defaultTerms` unless overridden
+  const createBet = (
+    betId: anchor.BN,
+    overrides: Partial<ReturnType<typeof defaultTerms>> = {},
+    options: {
+      userA?: anchor.web3.PublicKey;
+      userB?: anchor.web3.PublicKey;
+      accounts?: Record<string, any>;
+      signers?: anchor.web3.Keypair[];
+    } = {}
+  ) =>
+    program.methods
+      .createBet(
+        options.userA ?? userA.publicKey,
+        options.userB ?? userB.publicKey,
+        arbiter.publicKey,
+        betId,
+        { ...defaultTerms(), ...overrides }
+      )
+      .accounts({
+        payer: provider.wallet.publicKey,
+        config: configPda,
+        bet: deriveBetPda(betId, options.userA)[0],
+        protocolTreasury: protocolTreasury.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...options.accounts,
+      })
+      .signers(options.signers ?? [])
+      .rpc();
+
+  // Bet-owned token vault: mint, vault PDA and token program for the token accounts
+  const tokenAccounts = (
+    bet: anchor.web3.PublicKey,
+    mint: anchor.web3.PublicKey,
+    tokenProgram: anchor.web3.PublicKey
+  ) => ({
+    mint,
+    vault: anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), bet.toBuffer()],
+      program.programId
+    )[0],
+    tokenProgram,
+  });
+
+  // Pyth v2 price account: only the fields resolve_with_oracle reads
+  const pythPriceData = (price: anchor.BN, conf: anchor.BN, expo: number, publishTime: number) => {
+    const data = Buffer.alloc(240);
+    data.writeUInt32LE(0xa1b2c3d4, 0); // magic
+    data.writeUInt32LE(2, 4); // version
+    data.writeUInt32LE(3, 8); // price account
+    data.writeInt32LE(expo, 20);
+    new anchor.BN(publishTime).toArrayLike(Buffer, "le", 8).copy(data, 96);
+    price.toArrayLike(Buffer, "le", 8).copy(data, 208);
+    conf.toArrayLike(Buffer, "le", 8).copy(data, 216);
+    data.writeUInt32LE(1, 224); // trading
+    return data;
+  };
+
+  const writePythPrice = (feed: anchor.web3.PublicKey, data: Buffer) =>
+    mockPyth.methods
+      .write(new anchor.BN(0), data)
+      .accounts({ price: feed })
+      .rpc();
+
+  // New price account owned by the mock Pyth program
+  const createPythFeed = async (data: Buffer) => {
+    const feed = anchor.web3.Keypair.generate();
+    await mockPyth.methods
+      .write(new anchor.BN(0), data)
+      .accounts({ price: feed.publicKey })
+      .preInstructions([
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: provider.wallet.publicKey,
+          newAccountPubkey: feed.publicKey,
+          space: data.length,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(data.length),
+          programId: mockPyth.programId,
+        }),
+      ])
+      .signers([feed])
+      .rpc();
+    return feed.publicKey;
+  };
+
   before(async () => {
     // Airdrop SOL to test wallets
     userA = anchor.web3.Keypair.generate();
@@ -44,57 +196,69 @@ describe("duel_crowd_bets", () => {
     }
 
     // Derive bet PDA
-    [betPda, betBump] = anchor.web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("bet"),
-        arbiter.publicKey.toBuffer(),
-        userA.publicKey.toBuffer(),
-        userB.publicKey.toBuffer(),
-      ],
-      program.programId
-    );
+    [betPda, betBump] = deriveBetPda(new anchor.BN(0));
   });
 
-  it("Creates a bet", async () => {
-    const now = Math.floor(Date.now() / 1000);
-    const deadlineDuel = new anchor.BN(now + 3600); // 1 hour
-    const deadlineCrowd = new anchor.BN(now + 7200); // 2 hours
-    const resolveTs = new anchor.BN(now + 10800); // 3 hours
-
-    const spreadBps = 200; // 2%
-    const creatorShareBps = 5000; // 50%
-    const arbiterShareBps = 2000; // 20%
-    const protocolShareBps = 3000; // 30%
+  it("Initializes the protocol config", async () => {
+    const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
 
-    const tx = await program.methods
-      .createBet(
-        userA.publicKey,
-        userB.publicKey,
-        arbiter.publicKey,
-        stakeAmount,
-        deadlineDuel,
-        deadlineCrowd,
-        resolveTs,
-        spreadBps,
-        creatorShareBps,
-        arbiterShareBps,
-        protocolShareBps
-      )
+    await program.methods
+      .initializeConfig(protocolTreasury.publicKey, 100, 1000, 1000)
       .accounts({
-        payer: provider.wallet.publicKey,
-        bet: betPda,
-        protocolTreasury: protocolTreasury.publicKey,
+        admin: provider.wallet.publicKey,
+        config: configPda,
+        program: program.programId,
+        programData,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
 
+    const config = await program.account.protocolConfig.fetch(configPda);
+    expect(config.admin.toString()).to.equal(provider.wallet.publicKey.toString());
+    expect(config.treasury.toString()).to.equal(protocolTreasury.publicKey.toString());
+  });
+
+  it("Rejects new bets while the program is paused", async () => {
+    await program.methods
+      .setPaused(true)
+      .accounts({ admin: provider.wallet.publicKey, config: configPda })
+      .rpc();
+
+    try {
+      await createBet(new anchor.BN(0));
+      expect.fail("createBet should fail while paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ProgramPaused");
+    }
+
+    await program.methods
+      .setPaused(false)
+      .accounts({ admin: provider.wallet.publicKey, config: configPda })
+      .rpc();
+  });
+
+  it("Creates a bet", async () => {
+    // Short timeline so the rest of the main flow can resolve this bet
+    const now = nowTs();
+    mainResolveTs = now + 15;
+    const tx = await createBet(new anchor.BN(0), {
+      deadlineDuel: new anchor.BN(now + 10),
+      deadlineCrowd: new anchor.BN(now + 14),
+      resolveTs: new anchor.BN(mainResolveTs),
+      resolveDeadline: new anchor.BN(now + 3600),
+    });
+
     console.log("Create bet transaction:", tx);
 
     const betAccount = await program.account.bet.fetch(betPda);
     expect(betAccount.userA.toString()).to.equal(userA.publicKey.toString());
     expect(betAccount.userB.toString()).to.equal(userB.publicKey.toString());
     expect(betAccount.arbiter.toString()).to.equal(arbiter.publicKey.toString());
-    expect(betAccount.stakeLamports.toString()).to.equal(stakeAmount.toString());
+    expect(betAccount.stakeA.toString()).to.equal(stakeAmount.toString());
+    expect(betAccount.stakeB.toString()).to.equal(stakeAmount.toString());
     expect(betAccount.userADeposited).to.be.false;
     expect(betAccount.userBDeposited).to.be.false;
   });
@@ -105,6 +269,7 @@ describe("duel_crowd_bets", () => {
       .accounts({
         participant: userA.publicKey,
         bet: betPda,
+        config: configPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([userA])
@@ -122,6 +287,7 @@ describe("duel_crowd_bets", () => {
       .accounts({
         participant: userB.publicKey,
         bet: betPda,
+        config: configPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([userB])
@@ -139,16 +305,17 @@ describe("duel_crowd_bets", () => {
         Buffer.from("support"),
         betPda.toBuffer(),
         bettor1.publicKey.toBuffer(),
-        Buffer.from([0]), // Side A
+        Buffer.from([0]), // Outcome 0 (A)
       ],
       program.programId
     );
 
     const tx = await program.methods
-      .supportBet({ a: {} }, supportAmount)
+      .supportBet(0, supportAmount)
       .accounts({
         bettor: bettor1.publicKey,
         bet: betPda,
+        config: configPda,
         supportPosition: supportPositionPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -158,7 +325,7 @@ describe("duel_crowd_bets", () => {
     console.log("Bettor1 support transaction:", tx);
 
     const betAccount = await program.account.bet.fetch(betPda);
-    expect(betAccount.netSupportA.toNumber()).to.be.greaterThan(0);
+    expect(betAccount.netSupport[0].toNumber()).to.be.greaterThan(0);
 
     const supportPosition = await program.account.supportPosition.fetch(supportPositionPda);
     expect(supportPosition.bettor.toString()).to.equal(bettor1.publicKey.toString());
@@ -170,16 +337,17 @@ describe("duel_crowd_bets", () => {
         Buffer.from("support"),
         betPda.toBuffer(),
         bettor2.publicKey.toBuffer(),
-        Buffer.from([1]), // Side B
+        Buffer.from([1]), // Outcome 1 (B)
       ],
       program.programId
     );
 
     const tx = await program.methods
-      .supportBet({ b: {} }, supportAmount)
+      .supportBet(1, supportAmount)
       .accounts({
         bettor: bettor2.publicKey,
         bet: betPda,
+        config: configPda,
         supportPosition: supportPositionPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -189,15 +357,14 @@ describe("duel_crowd_bets", () => {
     console.log("Bettor2 support transaction:", tx);
 
     const betAccount = await program.account.bet.fetch(betPda);
-    expect(betAccount.netSupportB.toNumber()).to.be.greaterThan(0);
+    expect(betAccount.netSupport[1].toNumber()).to.be.greaterThan(0);
   });
 
   it("Arbiter declares winner (Side A)", async () => {
-    // Wait for resolve time (in real scenario)
-    // For testing, we'll assume enough time has passed
+    await sleepUntil(mainResolveTs);
 
     const tx = await program.methods
-      .declareWinner({ a: {} })
+      .declareWinner(0)
       .accounts({
         arbiter: arbiter.publicKey,
         bet: betPda,
@@ -233,13 +400,29 @@ describe("duel_crowd_bets", () => {
     expect(userABalanceAfter).to.be.greaterThan(userABalanceBefore);
   });
 
+  it("Winner cannot withdraw principal twice", async () => {
+    try {
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: userA.publicKey,
+          bet: betPda,
+        })
+        .signers([userA])
+        .rpc();
+      expect.fail("second principal withdrawal should fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("PrincipalAlreadyClaimed");
+    }
+  });
+
   it("Winning bettor (Bettor1) claims support reward", async () => {
     const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
       [
         Buffer.from("support"),
         betPda.toBuffer(),
         bettor1.publicKey.toBuffer(),
-        Buffer.from([0]), // Side A
+        Buffer.from([0]), // Outcome 0 (A)
       ],
       program.programId
     );
@@ -266,23 +449,2543 @@ describe("duel_crowd_bets", () => {
   });
 
   it("Withdraws spread fees", async () => {
-    const tx = await program.methods
-      .withdrawSpread()
+    const shares = [
+      { share: { creatorA: {} }, recipient: userA.publicKey },
+      { share: { creatorB: {} }, recipient: userB.publicKey },
+      { share: { arbiter: {} }, recipient: arbiter.publicKey },
+      { share: { protocol: {} }, recipient: protocolTreasury.publicKey },
+    ];
+
+    for (const { share, recipient } of shares) {
+      const tx = await program.methods
+        .withdrawSpread(share)
+        .accounts({
+          caller: provider.wallet.publicKey,
+          bet: betPda,
+          recipient,
+        })
+        .rpc();
+
+      console.log("Withdraw spread transaction:", tx);
+    }
+
+    const betAccount = await program.account.bet.fetch(betPda);
+    expect(betAccount.settlementClaims & 0b111100).to.equal(0b111100);
+  });
+
+  it("Rejects spread withdrawal to an unrelated recipient", async () => {
+    try {
+      await program.methods
+        .withdrawSpread({ arbiter: {} })
+        .accounts({
+          caller: provider.wallet.publicKey,
+          bet: betPda,
+          recipient: bettor1.publicKey,
+        })
+        .rpc();
+      expect.fail("spread withdrawal to an unrelated recipient should fail");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidRecipient");
+    }
+  });
+
+  it("Bettors close their positions and the bet is closed", async () => {
+    const positions = [
+      { bettor: bettor1, side: 0 },
+      { bettor: bettor2, side: 1 },
+    ];
+
+    for (const { bettor, side } of positions) {
+      const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("support"),
+          betPda.toBuffer(),
+          bettor.publicKey.toBuffer(),
+          Buffer.from([side]),
+        ],
+        program.programId
+      );
+
+      // The losing bettor still has to claim (with zero payout) before closing
+      const position = await program.account.supportPosition.fetch(supportPositionPda);
+      if (!position.claimed) {
+        await program.methods
+          .claimSupport()
+          .accounts({
+            bettor: bettor.publicKey,
+            bet: betPda,
+            supportPosition: supportPositionPda,
+          })
+          .signers([bettor])
+          .rpc();
+      }
+
+      await program.methods
+        .closeSupportPosition()
+        .accounts({
+          bettor: bettor.publicKey,
+          bet: betPda,
+          supportPosition: supportPositionPda,
+        })
+        .signers([bettor])
+        .rpc();
+    }
+
+    const betAccount = await program.account.bet.fetch(betPda);
+    expect(betAccount.openPositions).to.equal(0);
+
+    await program.methods
+      .closeBet()
       .accounts({
         caller: provider.wallet.publicKey,
         bet: betPda,
+        payer: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    expect(await provider.connection.getAccountInfo(betPda)).to.be.null;
+  });
+
+  it("Cancels an unfunded bet and refunds the deposited stake", async () => {
+    // Same trio, new bet_id: a rematch gets its own PDA
+    const cancelBetId = new anchor.BN(1);
+    const [cancelBetPda] = deriveBetPda(cancelBetId);
+
+    const deadlineDuel = nowTs() + 3;
+    await createBet(cancelBetId, { deadlineDuel: new anchor.BN(deadlineDuel) });
+
+    await program.methods
+      .depositParticipant()
+      .accounts({
+        participant: userA.publicKey,
+        bet: cancelBetPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    // User B never shows up; wait for deadline_duel to pass
+    await sleepUntil(deadlineDuel);
+
+    await program.methods
+      .cancelBet()
+      .accounts({
+        caller: provider.wallet.publicKey,
+        bet: cancelBetPda,
+      })
+      .rpc();
+
+    let betAccount = await program.account.bet.fetch(cancelBetPda);
+    expect(betAccount.status).to.deep.equal({ cancelled: {} });
+
+    const userABalanceBefore = await provider.connection.getBalance(userA.publicKey);
+
+    await program.methods
+      .refundParticipant()
+      .accounts({
+        participant: userA.publicKey,
+        bet: cancelBetPda,
+      })
+      .signers([userA])
+      .rpc();
+
+    // The provider pays the fee, so user A gets back exactly the stake
+    const userABalanceAfter = await provider.connection.getBalance(userA.publicKey);
+    expect(userABalanceAfter - userABalanceBefore).to.equal(stakeAmount.toNumber());
+
+    betAccount = await program.account.bet.fetch(cancelBetPda);
+    expect(betAccount.settlementClaims & 1).to.equal(1); // CLAIM_PRINCIPAL_A
+  });
+
+  it("Duelists and arbiter mutually cancel a funded bet", async () => {
+    const mutualBetId = new anchor.BN(2);
+    const [mutualBetPda] = deriveBetPda(mutualBetId);
+
+    await createBet(mutualBetId);
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: mutualBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        mutualBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: mutualBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    await program.methods
+      .mutualCancel()
+      .accounts({
         userA: userA.publicKey,
         userB: userB.publicKey,
         arbiter: arbiter.publicKey,
-        protocolTreasury: protocolTreasury.publicKey,
+        bet: mutualBetPda,
       })
+      .signers([userA, userB, arbiter])
       .rpc();
 
-    console.log("Withdraw spread transaction:", tx);
-
-    const betAccount = await program.account.bet.fetch(betPda);
+    // The fee goes back to the crowd, so nothing is left in the spread pools
+    const betAccount = await program.account.bet.fetch(mutualBetPda);
+    expect(betAccount.status).to.deep.equal({ cancelled: {} });
     expect(betAccount.spreadPoolCreators.toNumber()).to.equal(0);
     expect(betAccount.spreadPoolArbiter.toNumber()).to.equal(0);
     expect(betAccount.spreadPoolProtocol.toNumber()).to.equal(0);
+
+    for (const user of [userA, userB]) {
+      const balanceBefore = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .refundParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: mutualBetPda,
+        })
+        .signers([user])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(user.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(stakeAmount.toNumber());
+    }
+
+    const position = await program.account.supportPosition.fetch(supportPositionPda);
+    expect(position.grossAmount.toString()).to.equal(supportAmount.toString());
+
+    const bettorBalanceBefore = await provider.connection.getBalance(bettor1.publicKey);
+    await program.methods
+      .refundSupport()
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: mutualBetPda,
+        supportPosition: supportPositionPda,
+      })
+      .signers([bettor1])
+      .rpc();
+    const bettorBalanceAfter = await provider.connection.getBalance(bettor1.publicKey);
+    expect(bettorBalanceAfter - bettorBalanceBefore).to.equal(supportAmount.toNumber());
+  });
+
+  it("Opens a challenge that only an allowlisted wallet can accept", async () => {
+    const openBetId = new anchor.BN(3);
+    const [openBetPda] = deriveBetPda(openBetId);
+    const underdogStake = stakeAmount.divn(3);
+
+    await createBet(
+      openBetId,
+      {
+        stakeB: underdogStake, // 3:1 against the challenger
+        allowlist: [userB.publicKey],
+      },
+      { userB: anchor.web3.PublicKey.default } // open challenge
+    );
+
+    try {
+      await program.methods
+        .acceptChallenge()
+        .accounts({
+          challenger: bettor1.publicKey,
+          bet: openBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([bettor1])
+        .rpc();
+      expect.fail("Non-allowlisted wallet should not accept the challenge");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("NotAllowedToAccept");
+    }
+
+    await program.methods
+      .acceptChallenge()
+      .accounts({
+        challenger: userB.publicKey,
+        bet: openBetPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(openBetPda);
+    expect(betAccount.userB.toString()).to.equal(userB.publicKey.toString());
+    expect(betAccount.userBDeposited).to.be.true;
+    expect(betAccount.principalB.toString()).to.equal(underdogStake.toString());
+  });
+
+  it("Pays the underdog of a 3:1 duel both stakes", async () => {
+    const asymmetricBetId = new anchor.BN(30);
+    const [asymmetricBetPda] = deriveBetPda(asymmetricBetId);
+    const underdogStake = stakeAmount.divn(3);
+
+    const timeline = quickTimeline();
+    await createBet(asymmetricBetId, { ...timeline, stakeB: underdogStake });
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: asymmetricBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    let betAccount = await program.account.bet.fetch(asymmetricBetPda);
+    expect(betAccount.principalA.toString()).to.equal(stakeAmount.toString());
+    expect(betAccount.principalB.toString()).to.equal(underdogStake.toString());
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(1)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: asymmetricBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const balanceBefore = await provider.connection.getBalance(userB.publicKey);
+    await program.methods
+      .withdrawPrincipal()
+      .accounts({
+        winner: userB.publicKey,
+        bet: asymmetricBetPda,
+      })
+      .signers([userB])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(userB.publicKey);
+
+    // principal_a + principal_b: the favourite's 1 SOL plus the underdog's own third
+    expect(balanceAfter - balanceBefore).to.equal(stakeAmount.add(underdogStake).toNumber());
+  });
+
+  it("Runs a team duel where every roster member funds their own stake", async () => {
+    const teamBetId = new anchor.BN(4);
+    const [teamBetPda] = deriveBetPda(teamBetId);
+
+    // Room for the roster, four deposits and a supporter before the crowd closes
+    const now = nowTs();
+    const resolveTs = now + 13;
+    await createBet(teamBetId, {
+      deadlineDuel: new anchor.BN(now + 10),
+      deadlineCrowd: new anchor.BN(now + 12),
+      resolveTs: new anchor.BN(resolveTs),
+      resolveDeadline: new anchor.BN(now + 3600),
+    });
+
+    const memberStake = stakeAmount.divn(2);
+    await program.methods
+      .setTeams(
+        [
+          { member: userA.publicKey, stake: stakeAmount },
+          { member: bettor1.publicKey, stake: memberStake },
+        ],
+        [
+          { member: userB.publicKey, stake: stakeAmount },
+          { member: bettor2.publicKey, stake: memberStake },
+        ]
+      )
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        bet: teamBetPda,
+      })
+      .signers([userA, userB])
+      .rpc();
+
+    let betAccount = await program.account.bet.fetch(teamBetPda);
+    expect(betAccount.stakeA.toString()).to.equal(stakeAmount.add(memberStake).toString());
+
+    await program.methods
+      .depositParticipant()
+      .accounts({
+        participant: userA.publicKey,
+        bet: teamBetPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([userA])
+      .rpc();
+
+    // Side A is only funded once every member has deposited
+    betAccount = await program.account.bet.fetch(teamBetPda);
+    expect(betAccount.userADeposited).to.be.false;
+
+    await program.methods
+      .depositParticipant()
+      .accounts({
+        participant: bettor1.publicKey,
+        bet: teamBetPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    betAccount = await program.account.bet.fetch(teamBetPda);
+    expect(betAccount.userADeposited).to.be.true;
+    expect(betAccount.principalA.toString()).to.equal(stakeAmount.add(memberStake).toString());
+    expect(betAccount.teamA[1].principal.toString()).to.equal(memberStake.toString());
+
+    for (const member of [userB, bettor2]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: member.publicKey,
+          bet: teamBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    // The provider wallet backs team A, filling the creators' spread pool
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        teamBetPda.toBuffer(),
+        provider.wallet.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: provider.wallet.publicKey,
+        bet: teamBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await sleepUntil(resolveTs);
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: teamBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    betAccount = await program.account.bet.fetch(teamBetPda);
+    const pot = betAccount.principalA.add(betAccount.principalB);
+    const perMemberSpread = betAccount.spreadPoolCreators.divn(4);
+    expect(perMemberSpread.toNumber()).to.be.greaterThan(0);
+
+    // Team A splits the whole pot pro rata to what each member deposited
+    for (const member of [userA, bettor1]) {
+      const principal = betAccount.teamA.find((m) => m.member.equals(member.publicKey)).principal;
+      const balanceBefore = await provider.connection.getBalance(member.publicKey);
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: member.publicKey,
+          bet: teamBetPda,
+        })
+        .signers([member])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(member.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(
+        pot.mul(principal).div(betAccount.principalA).toNumber()
+      );
+    }
+
+    // Every member of both rosters claims an equal cut of the creators' spread
+    const shares: [anchor.web3.Keypair, any][] = [
+      [userA, { creatorA: {} }],
+      [bettor1, { creatorA: {} }],
+      [userB, { creatorB: {} }],
+      [bettor2, { creatorB: {} }],
+    ];
+    for (const [member, share] of shares) {
+      const balanceBefore = await provider.connection.getBalance(member.publicKey);
+      await program.methods
+        .withdrawSpread(share)
+        .accounts({
+          caller: provider.wallet.publicKey,
+          bet: teamBetPda,
+          recipient: member.publicKey,
+        })
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(member.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(perMemberSpread.toNumber());
+    }
+  });
+
+  it("Advances the winner of a tournament final and pays the prize", async () => {
+    const tournamentId = new anchor.BN(0);
+    const [tournamentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("tournament"),
+        provider.wallet.publicKey.toBuffer(),
+        tournamentId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .createTournament(tournamentId, arbiter.publicKey, [userA.publicKey, userB.publicKey])
+      .accounts({
+        organizer: provider.wallet.publicKey,
+        tournament: tournamentPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const prize = new anchor.BN(200_000_000); // 0.2 SOL
+    await program.methods
+      .fundPrizePool(prize)
+      .accounts({
+        funder: provider.wallet.publicKey,
+        tournament: tournamentPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Two-player bracket: match 0 is the final
+    const finalBetId = new anchor.BN(5);
+    const [finalBetPda] = deriveBetPda(finalBetId);
+
+    const timeline = quickTimeline();
+    await createBet(finalBetId, timeline);
+
+    await program.methods
+      .registerMatch(0)
+      .accounts({
+        organizer: provider.wallet.publicKey,
+        payer: provider.wallet.publicKey,
+        tournament: tournamentPda,
+        bet: finalBetPda,
+      })
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: finalBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(1)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: finalBetPda,
+        tournament: tournamentPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const tournament = await program.account.tournament.fetch(tournamentPda);
+    expect(tournament.champion.toString()).to.equal(userB.publicKey.toString());
+
+    const userBBalanceBefore = await provider.connection.getBalance(userB.publicKey);
+
+    await program.methods
+      .claimTournamentPrize()
+      .accounts({
+        champion: userB.publicKey,
+        tournament: tournamentPda,
+      })
+      .signers([userB])
+      .rpc();
+
+    const userBBalanceAfter = await provider.connection.getBalance(userB.publicKey);
+    expect(userBBalanceAfter).to.be.greaterThan(userBBalanceBefore);
+  });
+
+  it("Runs a four-player bracket with a champion market", async () => {
+    const tournamentId = new anchor.BN(1);
+    const [tournamentPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("tournament"),
+        provider.wallet.publicKey.toBuffer(),
+        tournamentId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const players = [userA, userB, bettor1, bettor2];
+
+    await program.methods
+      .createTournament(
+        tournamentId,
+        arbiter.publicKey,
+        players.map((player) => player.publicKey)
+      )
+      .accounts({
+        organizer: provider.wallet.publicKey,
+        tournament: tournamentPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const fundPrizePool = () =>
+      program.methods
+        .fundPrizePool(new anchor.BN(100_000_000)) // 0.1 SOL
+        .accounts({
+          funder: provider.wallet.publicKey,
+          tournament: tournamentPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    // The pause blocks new money into the prize pool too
+    await program.methods
+      .setPaused(true)
+      .accounts({ admin: provider.wallet.publicKey, config: configPda })
+      .rpc();
+    try {
+      await fundPrizePool();
+      expect.fail("fundPrizePool should fail while paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ProgramPaused");
+    }
+    await program.methods
+      .setPaused(false)
+      .accounts({ admin: provider.wallet.publicKey, config: configPda })
+      .rpc();
+
+    await fundPrizePool();
+
+    const registerMatch = (matchIndex: number, bet: anchor.web3.PublicKey, extra = {}) =>
+      program.methods
+        .registerMatch(matchIndex)
+        .accounts({
+          organizer: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          tournament: tournamentPda,
+          bet,
+          ...extra,
+        })
+        .rpc();
+
+    const deposit = async (bet: anchor.web3.PublicKey, users: anchor.web3.Keypair[]) => {
+      for (const user of users) {
+        await program.methods
+          .depositParticipant()
+          .accounts({
+            participant: user.publicKey,
+            bet,
+            config: configPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+      }
+    };
+
+    // Champion market: one outcome per player, in seed order
+    const marketBetId = new anchor.BN(15);
+    const [marketBetPda] = deriveBetPda(marketBetId);
+    await createBet(marketBetId, { outcomeCount: 4 });
+
+    // Only the bet's payer can hand it over to the tournament
+    const stranger = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .registerMatch(255)
+        .accounts({
+          organizer: provider.wallet.publicKey,
+          payer: stranger.publicKey,
+          tournament: tournamentPda,
+          bet: marketBetPda,
+        })
+        .signers([stranger])
+        .rpc();
+      expect.fail("register_match should require the bet payer");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidPayer");
+    }
+
+    await registerMatch(255, marketBetPda);
+
+    // A bet that already holds a stake cannot become a match
+    const fundedBetId = new anchor.BN(16);
+    const [fundedBetPda] = deriveBetPda(fundedBetId);
+    await createBet(fundedBetId);
+    await deposit(fundedBetPda, [userA]);
+    try {
+      await registerMatch(0, fundedBetPda);
+      expect.fail("register_match should reject funded bets");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AlreadyDeposited");
+    }
+
+    // The first bet for match 0 is never funded; once cancelled, the slot reopens
+    const stalledBetId = new anchor.BN(17);
+    const [stalledBetPda] = deriveBetPda(stalledBetId);
+    const stalledDeadline = nowTs() + 3;
+    await createBet(stalledBetId, { deadlineDuel: new anchor.BN(stalledDeadline) });
+    await registerMatch(0, stalledBetPda);
+
+    const semiABetId = new anchor.BN(18);
+    const [semiABetPda] = deriveBetPda(semiABetId);
+    await createBet(semiABetId);
+    try {
+      await registerMatch(0, semiABetPda, { previousBet: stalledBetPda });
+      expect.fail("An open match should keep its slot");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MatchAlreadyRegistered");
+    }
+
+    await sleepUntil(stalledDeadline);
+    await program.methods
+      .cancelBet()
+      .accounts({ caller: provider.wallet.publicKey, bet: stalledBetPda })
+      .rpc();
+
+    // Semifinals: userA vs userB and bettor1 vs bettor2
+    const semiTimeline = quickTimeline();
+    const replacementBetId = new anchor.BN(19);
+    const [replacementBetPda] = deriveBetPda(replacementBetId);
+    await createBet(replacementBetId, semiTimeline);
+    await registerMatch(0, replacementBetPda, { previousBet: stalledBetPda });
+
+    const semiBBetId = new anchor.BN(20);
+    const [semiBBetPda] = deriveBetPda(semiBBetId, bettor1.publicKey);
+    await createBet(semiBBetId, semiTimeline, {
+      userA: bettor1.publicKey,
+      userB: bettor2.publicKey,
+    });
+    await registerMatch(1, semiBBetPda);
+
+    await deposit(replacementBetPda, [userA, userB]);
+    await deposit(semiBBetPda, [bettor1, bettor2]);
+
+    const supportChampion = (bettor: anchor.web3.Keypair, outcome: number) =>
+      program.methods
+        .supportBet(outcome, supportAmount)
+        .accounts({
+          bettor: bettor.publicKey,
+          bet: marketBetPda,
+          config: configPda,
+          supportPosition: anchor.web3.PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("support"),
+              marketBetPda.toBuffer(),
+              bettor.publicKey.toBuffer(),
+              Buffer.from([outcome]),
+            ],
+            program.programId
+          )[0],
+          tournament: tournamentPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc();
+
+    // The crowd backs userB (outcome 1) to take the title
+    await supportChampion(bettor1, 1);
+
+    await sleepUntil(semiTimeline.resolveTs.toNumber());
+
+    const declare = (bet: anchor.web3.PublicKey, outcome: number) =>
+      program.methods
+        .declareWinner(outcome)
+        .accounts({ arbiter: arbiter.publicKey, bet, tournament: tournamentPda })
+        .signers([arbiter])
+        .rpc();
+
+    await declare(replacementBetPda, 1); // userB
+    await declare(semiBBetPda, 0); // bettor1
+
+    let tournament = await program.account.tournament.fetch(tournamentPda);
+    expect(tournament.advancing[0].toString()).to.equal(userB.publicKey.toString());
+    expect(tournament.advancing[1].toString()).to.equal(bettor1.publicKey.toString());
+    expect(tournament.champion).to.be.null;
+
+    // Final: the semifinal winners meet in match 2
+    const finalTimeline = quickTimeline();
+    const finalBetId = new anchor.BN(21);
+    const [finalBetPda] = deriveBetPda(finalBetId, userB.publicKey);
+    await createBet(finalBetId, finalTimeline, {
+      userA: userB.publicKey,
+      userB: bettor1.publicKey,
+    });
+    await registerMatch(2, finalBetPda);
+    await deposit(finalBetPda, [userB, bettor1]);
+
+    await sleepUntil(finalTimeline.resolveTs.toNumber());
+    await declare(finalBetPda, 0); // userB
+
+    tournament = await program.account.tournament.fetch(tournamentPda);
+    expect(tournament.advancing[2].toString()).to.equal(userB.publicKey.toString());
+    expect(tournament.champion.toString()).to.equal(userB.publicKey.toString());
+
+    // deadline_crowd is still hours away, but the title is already decided
+    try {
+      await supportChampion(bettor2, 1);
+      expect.fail("The champion market should close with the final");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("TournamentFinished");
+    }
+
+    await program.methods
+      .resolveChampionMarket()
+      .accounts({
+        caller: provider.wallet.publicKey,
+        tournament: tournamentPda,
+        bet: marketBetPda,
+      })
+      .rpc();
+
+    const market = await program.account.bet.fetch(marketBetPda);
+    expect(market.status).to.deep.equal({ resolved: {} });
+    expect(market.winningOutcome).to.equal(1);
+
+    try {
+      await program.methods
+        .claimTournamentPrize()
+        .accounts({ champion: bettor1.publicKey, tournament: tournamentPda })
+        .signers([bettor1])
+        .rpc();
+      expect.fail("Only the champion can claim the prize");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidWinner");
+    }
+
+    const championBalanceBefore = await provider.connection.getBalance(userB.publicKey);
+    await program.methods
+      .claimTournamentPrize()
+      .accounts({ champion: userB.publicKey, tournament: tournamentPda })
+      .signers([userB])
+      .rpc();
+    const championBalanceAfter = await provider.connection.getBalance(userB.publicKey);
+    expect(championBalanceAfter).to.be.greaterThan(championBalanceBefore);
+  });
+
+  it("Resolves a best-of-3 series once a side reaches two wins", async () => {
+    const seriesBetId = new anchor.BN(6);
+    const [seriesBetPda] = deriveBetPda(seriesBetId);
+
+    const timeline = quickTimeline();
+    await createBet(seriesBetId, timeline);
+
+    await program.methods
+      .setSeries(3)
+      .accounts({
+        payer: provider.wallet.publicKey,
+        bet: seriesBetPda,
+      })
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: seriesBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    for (const side of [{ a: {} }, { b: {} }, { a: {} }]) {
+      await program.methods
+        .recordGame(side)
+        .accounts({
+          arbiter: arbiter.publicKey,
+          bet: seriesBetPda,
+        })
+        .signers([arbiter])
+        .rpc();
+    }
+
+    const betAccount = await program.account.bet.fetch(seriesBetPda);
+    expect(betAccount.winsA).to.equal(2);
+    expect(betAccount.winsB).to.equal(1);
+    expect(betAccount.status).to.deep.equal({ resolved: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ a: {} });
+  });
+
+  it("Blocks payouts during the dispute window and lets the fallback overturn", async () => {
+    const disputedBetId = new anchor.BN(7);
+    const [disputedBetPda] = deriveBetPda(disputedBetId);
+    const fallbackArbiter = anchor.web3.Keypair.generate();
+
+    const timeline = quickTimeline();
+    await createBet(disputedBetId, timeline);
+
+    // A duelist can't be the one who settles disputes
+    try {
+      await program.methods
+        .setDisputeTerms(userB.publicKey, new anchor.BN(3600), new anchor.BN(100_000_000))
+        .accounts({
+          userA: userA.publicKey,
+          userB: userB.publicKey,
+          bet: disputedBetPda,
+        })
+        .signers([userA, userB])
+        .rpc();
+      expect.fail("setDisputeTerms should reject a duelist as fallback");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidDisputeTerms");
+    }
+
+    await program.methods
+      .setDisputeTerms(fallbackArbiter.publicKey, new anchor.BN(3600), new anchor.BN(100_000_000))
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        bet: disputedBetPda,
+      })
+      .signers([userA, userB])
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: disputedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: disputedBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    try {
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: userA.publicKey,
+          bet: disputedBetPda,
+        })
+        .signers([userA])
+        .rpc();
+      expect.fail("Payouts should be blocked during the dispute window");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ResultNotFinal");
+    }
+
+    await program.methods
+      .disputeResult()
+      .accounts({
+        disputer: userB.publicKey,
+        bet: disputedBetPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+    await program.methods
+      .resolveDispute(1)
+      .accounts({
+        fallbackArbiter: fallbackArbiter.publicKey,
+        bet: disputedBetPda,
+        disputer: userB.publicKey,
+      })
+      .signers([fallbackArbiter])
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(disputedBetPda);
+    expect(betAccount.disputeStatus).to.deep.equal({ overturned: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ b: {} });
+  });
+
+  // Dispute terms signed by both duelists, deposits made and side A declared
+  const declareDisputedBet = async (
+    betId: anchor.BN,
+    fallbackArbiter: anchor.web3.PublicKey,
+    disputeWindow: number
+  ) => {
+    const [disputedBetPda] = deriveBetPda(betId);
+    const timeline = quickTimeline();
+    await createBet(betId, timeline);
+
+    await program.methods
+      .setDisputeTerms(fallbackArbiter, new anchor.BN(disputeWindow), new anchor.BN(100_000_000))
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        bet: disputedBetPda,
+      })
+      .signers([userA, userB])
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: disputedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: disputedBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    return disputedBetPda;
+  };
+
+  const disputeAsUserB = (bet: anchor.web3.PublicKey) =>
+    program.methods
+      .disputeResult()
+      .accounts({
+        disputer: userB.publicKey,
+        bet,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([userB])
+      .rpc();
+
+  it("Keeps the result the fallback upholds and sends the bond to the protocol", async () => {
+    const fallbackArbiter = anchor.web3.Keypair.generate();
+    const disputedBetPda = await declareDisputedBet(
+      new anchor.BN(32),
+      fallbackArbiter.publicKey,
+      3600
+    );
+    const protocolPoolBefore = (await program.account.bet.fetch(disputedBetPda)).spreadPoolProtocol;
+
+    const balanceBefore = await provider.connection.getBalance(userB.publicKey);
+    await disputeAsUserB(disputedBetPda);
+
+    await program.methods
+      .resolveDispute(0)
+      .accounts({
+        fallbackArbiter: fallbackArbiter.publicKey,
+        bet: disputedBetPda,
+        disputer: userB.publicKey,
+      })
+      .signers([fallbackArbiter])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(userB.publicKey);
+
+    const betAccount = await program.account.bet.fetch(disputedBetPda);
+    expect(betAccount.disputeStatus).to.deep.equal({ upheld: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ a: {} });
+    expect(betAccount.spreadPoolProtocol.sub(protocolPoolBefore).toString()).to.equal(
+      betAccount.disputeBondPosted.toString()
+    );
+    expect(balanceBefore - balanceAfter).to.equal(betAccount.disputeBondPosted.toNumber());
+  });
+
+  it("Returns the bond when the fallback lets the dispute lapse", async () => {
+    const fallbackArbiter = anchor.web3.Keypair.generate();
+    const disputeWindow = 5;
+    const disputedBetPda = await declareDisputedBet(
+      new anchor.BN(33),
+      fallbackArbiter.publicKey,
+      disputeWindow
+    );
+
+    const balanceBefore = await provider.connection.getBalance(userB.publicKey);
+    await disputeAsUserB(disputedBetPda);
+
+    const expireDispute = () =>
+      program.methods
+        .expireDispute()
+        .accounts({
+          caller: provider.wallet.publicKey,
+          bet: disputedBetPda,
+          disputer: userB.publicKey,
+        })
+        .rpc();
+
+    // The fallback still has a full window after the dispute window closes
+    try {
+      await expireDispute();
+      expect.fail("expireDispute should wait for the fallback deadline");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ResolveDeadlineNotReached");
+    }
+
+    const { disputeEndsAt } = await program.account.bet.fetch(disputedBetPda);
+    await sleepUntil(disputeEndsAt.toNumber() + disputeWindow);
+
+    await expireDispute();
+    const balanceAfter = await provider.connection.getBalance(userB.publicKey);
+
+    const betAccount = await program.account.bet.fetch(disputedBetPda);
+    expect(betAccount.disputeStatus).to.deep.equal({ lapsed: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ a: {} });
+    expect(balanceAfter).to.equal(balanceBefore);
+  });
+
+  it("Resolves once two of three committee members agree", async () => {
+    const committeeBetId = new anchor.BN(8);
+    const [committeeBetPda] = deriveBetPda(committeeBetId);
+    const members = [arbiter, anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+
+    // Room for the committee, both deposits and a supporter before the crowd closes
+    const now = nowTs();
+    const resolveTs = now + 11;
+    await createBet(committeeBetId, {
+      deadlineDuel: new anchor.BN(now + 8),
+      deadlineCrowd: new anchor.BN(now + 10),
+      resolveTs: new anchor.BN(resolveTs),
+      resolveDeadline: new anchor.BN(now + 3600),
+    });
+
+    // A duelist can't vote on their own bet
+    try {
+      await program.methods
+        .setCommittee([members[0].publicKey, members[1].publicKey, userB.publicKey], 2)
+        .accounts({
+          userA: userA.publicKey,
+          userB: userB.publicKey,
+          bet: committeeBetPda,
+        })
+        .signers([userA, userB])
+        .rpc();
+      expect.fail("setCommittee should reject a duelist as member");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidCommittee");
+    }
+
+    await program.methods
+      .setCommittee(members.map((m) => m.publicKey), 2)
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        bet: committeeBetPda,
+      })
+      .signers([userA, userB])
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: committeeBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // The crowd fills the arbiter's share of the spread, which the committee splits
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        committeeBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: committeeBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    // Wait for resolve_ts
+    await sleepUntil(resolveTs);
+
+    const votes: [anchor.web3.Keypair, number][] = [
+      [members[0], 0],
+      [members[1], 1],
+      [members[2], 0],
+    ];
+    for (const [member, outcome] of votes) {
+      await program.methods
+        .voteWinner(outcome)
+        .accounts({
+          member: member.publicKey,
+          bet: committeeBetPda,
+        })
+        .signers([member])
+        .rpc();
+    }
+
+    const betAccount = await program.account.bet.fetch(committeeBetPda);
+    expect(betAccount.status).to.deep.equal({ resolved: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ a: {} });
+
+    // The two members who voted with the majority split the arbiter's share
+    const feePerVoter = betAccount.spreadPoolArbiter.divn(2);
+    expect(feePerVoter.toNumber()).to.be.greaterThan(0);
+    const withdrawFee = (member: anchor.web3.Keypair) =>
+      program.methods
+        .withdrawSpread({ arbiter: {} })
+        .accounts({
+          caller: provider.wallet.publicKey,
+          bet: committeeBetPda,
+          recipient: member.publicKey,
+        })
+        .rpc();
+
+    for (const member of [members[0], members[2]]) {
+      const balanceBefore = await provider.connection.getBalance(member.publicKey);
+      await withdrawFee(member);
+      const balanceAfter = await provider.connection.getBalance(member.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(feePerVoter.toNumber());
+    }
+
+    // The member outvoted gets nothing
+    try {
+      await withdrawFee(members[1]);
+      expect.fail("A minority voter should not be paid");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidRecipient");
+    }
+  });
+
+  it("Keeps the crowd closed until the arbiter posts the required bond", async () => {
+    const bondedBetId = new anchor.BN(9);
+    const [bondedBetPda] = deriveBetPda(bondedBetId);
+    const [arbiterBondPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("arbiter_bond"), bondedBetPda.toBuffer()],
+      program.programId
+    );
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        bondedBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    await createBet(bondedBetId);
+
+    const bondAmount = new anchor.BN(300_000_000); // 0.3 SOL
+    await program.methods
+      .setArbiterBond(bondAmount)
+      .accounts({
+        payer: provider.wallet.publicKey,
+        bet: bondedBetPda,
+      })
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: bondedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    const supportAccounts = {
+      bettor: bettor1.publicKey,
+      bet: bondedBetPda,
+      config: configPda,
+      supportPosition: supportPositionPda,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    };
+
+    try {
+      await program.methods
+        .supportBet(0, supportAmount)
+        .accounts(supportAccounts)
+        .signers([bettor1])
+        .rpc();
+      expect.fail("Crowd should stay closed without the arbiter bond");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ArbiterBondNotPosted");
+    }
+
+    await program.methods
+      .postArbiterBond()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: bondedBetPda,
+        config: configPda,
+        arbiterBond: arbiterBondPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const arbiterBond = await program.account.arbiterBond.fetch(arbiterBondPda);
+    expect(arbiterBond.amount.toString()).to.equal(bondAmount.toString());
+
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts(supportAccounts)
+      .signers([bettor1])
+      .rpc();
+  });
+
+  it("Returns the arbiter's bond with the fee after an uncontested resolution", async () => {
+    const bondedBetId = new anchor.BN(31);
+    const [bondedBetPda] = deriveBetPda(bondedBetId);
+    const [arbiterBondPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("arbiter_bond"), bondedBetPda.toBuffer()],
+      program.programId
+    );
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        bondedBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    // Room for the bond, both deposits and a supporter before the crowd closes
+    const now = nowTs();
+    const resolveTs = now + 11;
+    await createBet(bondedBetId, {
+      deadlineDuel: new anchor.BN(now + 8),
+      deadlineCrowd: new anchor.BN(now + 10),
+      resolveTs: new anchor.BN(resolveTs),
+      resolveDeadline: new anchor.BN(now + 3600),
+    });
+
+    const bondAmount = new anchor.BN(300_000_000); // 0.3 SOL
+    await program.methods
+      .setArbiterBond(bondAmount)
+      .accounts({
+        payer: provider.wallet.publicKey,
+        bet: bondedBetPda,
+      })
+      .rpc();
+
+    await program.methods
+      .postArbiterBond()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: bondedBetPda,
+        config: configPda,
+        arbiterBond: arbiterBondPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: bondedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: bondedBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    // Wait for resolve_ts
+    await sleepUntil(resolveTs);
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: bondedBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    // No dispute window: the result is final and the escrow can be closed
+    const fee = (await program.account.bet.fetch(bondedBetPda)).spreadPoolArbiter;
+    expect(fee.toNumber()).to.be.greaterThan(0);
+    const escrowLamports = await provider.connection.getBalance(arbiterBondPda);
+
+    const balanceBefore = await provider.connection.getBalance(arbiter.publicKey);
+    await program.methods
+      .releaseArbiterBond()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: bondedBetPda,
+        arbiterBond: arbiterBondPda,
+      })
+      .signers([arbiter])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(arbiter.publicKey);
+
+    // Bond and escrow rent come back with the arbiter's share of the spread
+    expect(escrowLamports).to.be.greaterThan(bondAmount.toNumber());
+    expect(balanceAfter - balanceBefore).to.equal(escrowLamports + fee.toNumber());
+    expect(await provider.connection.getAccountInfo(arbiterBondPda)).to.be.null;
+
+    const betAccount = await program.account.bet.fetch(bondedBetPda);
+    expect(betAccount.arbiterBondSettled).to.be.true;
+  });
+
+  it("Resolves a price bet from the Pyth feed without the arbiter", async () => {
+    const oracleBetId = new anchor.BN(10);
+    const [oracleBetPda] = deriveBetPda(oracleBetId);
+    // SOL/USD at 150.00 (expo -8), conf 0.10
+    const solPrice = new anchor.BN(15_000_000_000);
+    const solConf = new anchor.BN(10_000_000);
+    const priceFeed = await createPythFeed(pythPriceData(solPrice, solConf, -8, nowTs()));
+
+    // Loaded by the local validator from tests/fixtures/mock_pyth_price.json:
+    // same price, but published on 2024-06-01
+    const oldPriceFeed = new anchor.web3.PublicKey(
+      "HFfK5snhEaF4nuUKfiSyWHCpQxr8H9L14dw7EmhgTPbq"
+    );
+    const staleBetId = new anchor.BN(22);
+    const [staleBetPda] = deriveBetPda(staleBetId);
+
+    const timeline = quickTimeline();
+    await createBet(oracleBetId, timeline);
+    await createBet(staleBetId, timeline);
+
+    // A takes "SOL above 140"
+    const setOracle = (feed: anchor.web3.PublicKey, bet = oracleBetPda) =>
+      program.methods
+        .setOracle(
+          new anchor.BN(14_000_000_000),
+          -8,
+          { above: {} },
+          new anchor.BN(60),
+          50 // conf up to 0.5% of the price
+        )
+        .accounts({
+          userA: userA.publicKey,
+          userB: userB.publicKey,
+          bet,
+          priceFeed: feed,
+        })
+        .signers([userA, userB])
+        .rpc();
+
+    // Same bytes as a Pyth price account, but not owned by the Pyth program
+    try {
+      await setOracle(new anchor.web3.PublicKey("8mvdyYctuxcVBEeCZTca7WuoMtDYkNwXUNHSHpzwFDRd"));
+      expect.fail("set_oracle should reject feeds not owned by Pyth");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidPriceFeed");
+    }
+
+    await setOracle(priceFeed);
+    await setOracle(oldPriceFeed, staleBetPda);
+
+    for (const bet of [oracleBetPda, staleBetPda]) {
+      for (const user of [userA, userB]) {
+        await program.methods
+          .depositParticipant()
+          .accounts({
+            participant: user.publicKey,
+            bet,
+            config: configPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+      }
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    try {
+      await program.methods
+        .declareWinner(1)
+        .accounts({
+          arbiter: arbiter.publicKey,
+          bet: oracleBetPda,
+        })
+        .signers([arbiter])
+        .rpc();
+      expect.fail("Oracle bets should not accept a manual result");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("OracleResolution");
+    }
+
+    const resolveWithOracle = (bet: anchor.web3.PublicKey, feed: anchor.web3.PublicKey) =>
+      program.methods
+        .resolveWithOracle()
+        .accounts({
+          caller: bettor2.publicKey,
+          bet,
+          priceFeed: feed,
+        })
+        .signers([bettor2])
+        .rpc();
+
+    // Prices published before resolve_ts, or stamped in the future, don't count
+    const stalePrices = [
+      { bet: staleBetPda, feed: oldPriceFeed },
+      { bet: oracleBetPda, feed: priceFeed },
+    ];
+    for (const { bet, feed } of stalePrices) {
+      try {
+        await resolveWithOracle(bet, feed);
+        expect.fail("resolve_with_oracle should reject a price from before resolve_ts");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("StalePrice");
+      }
+    }
+
+    await writePythPrice(priceFeed, pythPriceData(solPrice, solConf, -8, nowTs() + 3600));
+    try {
+      await resolveWithOracle(oracleBetPda, priceFeed);
+      expect.fail("resolve_with_oracle should reject a price from the future");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("StalePrice");
+    }
+
+    // The feed publishes right at resolve_ts; anyone can resolve with it
+    await writePythPrice(
+      priceFeed,
+      pythPriceData(solPrice, solConf, -8, timeline.resolveTs.toNumber())
+    );
+
+    await resolveWithOracle(oracleBetPda, priceFeed);
+
+    const betAccount = await program.account.bet.fetch(oracleBetPda);
+    expect(betAccount.status).to.deep.equal({ resolved: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ a: {} });
+  });
+
+  it("Resolves from the game server's signed attestation", async () => {
+    const attestedBetId = new anchor.BN(11);
+    const [attestedBetPda] = deriveBetPda(attestedBetId);
+    const gameServer = anchor.web3.Keypair.generate();
+
+    const timeline = quickTimeline();
+    await createBet(attestedBetId, timeline);
+
+    // The payer alone can't pick the key that decides the duel
+    try {
+      await program.methods
+        .setAttestor(gameServer.publicKey)
+        .accounts({
+          userA: provider.wallet.publicKey,
+          userB: userB.publicKey,
+          bet: attestedBetPda,
+        })
+        .signers([userB])
+        .rpc();
+      expect.fail("setAttestor should require user A");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidParticipant");
+    }
+
+    await program.methods
+      .setAttestor(gameServer.publicKey)
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        bet: attestedBetPda,
+      })
+      .signers([userA, userB])
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: attestedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    // Message: bet || winner_side (1 = B) || timestamp (i64 LE)
+    const attestedAt = timeline.resolveTs;
+    const message = Buffer.concat([
+      attestedBetPda.toBuffer(),
+      Buffer.from([1]),
+      attestedAt.toArrayLike(Buffer, "le", 8),
+    ]);
+    const resolveAccounts = {
+      caller: bettor1.publicKey,
+      bet: attestedBetPda,
+      instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    };
+
+    // A signature from any other key is rejected
+    try {
+      await program.methods
+        .resolveWithAttestation({ b: {} }, attestedAt)
+        .accounts(resolveAccounts)
+        .preInstructions([
+          anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: bettor1.secretKey,
+            message,
+          }),
+        ])
+        .signers([bettor1])
+        .rpc();
+      expect.fail("Only the attestor's signature should resolve the bet");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidAttestation");
+    }
+
+    await program.methods
+      .resolveWithAttestation({ b: {} }, attestedAt)
+      .accounts(resolveAccounts)
+      .preInstructions([
+        anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: gameServer.secretKey,
+          message,
+        }),
+      ])
+      .signers([bettor1])
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(attestedBetPda);
+    expect(betAccount.status).to.deep.equal({ resolved: {} });
+    expect(betAccount.winnerSide).to.deep.equal({ b: {} });
+  });
+
+  it("Builds the arbiter's track record and gates new bets on it", async () => {
+    const [arbiterProfilePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("arbiter_profile"), arbiter.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .registerArbiter()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        arbiterProfile: arbiterProfilePda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const requirement = {
+      minResolved: new anchor.BN(1),
+      minOnTimeBps: 10_000,
+      maxOverturnedBps: 0,
+    };
+    const createGatedBet = (betId: anchor.BN) =>
+      createBet(
+        betId,
+        { ...quickTimeline(), arbiterRequirement: requirement },
+        { accounts: { arbiterProfile: arbiterProfilePda } }
+      );
+
+    // A fresh profile has no history yet
+    try {
+      await createGatedBet(new anchor.BN(13));
+      expect.fail("A fresh arbiter should not meet the requirement");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ArbiterTrackRecordTooShort");
+    }
+
+    // A tracked bet (profile passed and signed by the arbiter) resolved on time
+    const trackedBetId = new anchor.BN(12);
+    const [trackedBetPda] = deriveBetPda(trackedBetId);
+    const timeline = quickTimeline();
+    await createBet(trackedBetId, timeline, {
+      accounts: { arbiterProfile: arbiterProfilePda, arbiterSigner: arbiter.publicKey },
+      signers: [arbiter],
+    });
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: trackedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    // Wait for resolve_ts
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: trackedBetPda,
+        arbiterProfile: arbiterProfilePda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const profile = await program.account.arbiterProfile.fetch(arbiterProfilePda);
+    expect(profile.resolvedCount.toNumber()).to.equal(1);
+    expect(profile.onTimeCount.toNumber()).to.equal(1);
+    expect(profile.overturnedCount.toNumber()).to.equal(0);
+
+    await createGatedBet(new anchor.BN(13));
+
+    // The profile only gated this bet: without the arbiter's signature it isn't tracked
+    const gatedBet = await program.account.bet.fetch(deriveBetPda(new anchor.BN(13))[0]);
+    expect(gatedBet.arbiterProfile.toBase58()).to.equal(anchor.web3.PublicKey.default.toBase58());
+  });
+
+  it("Reassigns the arbiter with both duelists and the current arbiter", async () => {
+    const reassignedBetId = new anchor.BN(14);
+    const [reassignedBetPda] = deriveBetPda(reassignedBetId);
+    const newArbiter = anchor.web3.Keypair.generate();
+
+    await createBet(reassignedBetId);
+
+    // Without the arbiter's signature, the duelists have to wait for the arbiter timeout
+    try {
+      await program.methods
+        .reassignArbiter(newArbiter.publicKey)
+        .accounts({
+          userA: userA.publicKey,
+          userB: userB.publicKey,
+          arbiter: arbiter.publicKey,
+          bet: reassignedBetPda,
+        })
+        .signers([userA, userB])
+        .rpc();
+      expect.fail("Duelists alone should not reassign before the timeout");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ArbiterTimeoutNotReached");
+    }
+
+    await program.methods
+      .reassignArbiter(newArbiter.publicKey)
+      .accounts({
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+        arbiter: arbiter.publicKey,
+        bet: reassignedBetPda,
+      })
+      .signers([userA, userB, arbiter])
+      .rpc();
+
+    // The bet keeps its address: the arbiter is no longer part of the seeds
+    const betAccount = await program.account.bet.fetch(reassignedBetPda);
+    expect(betAccount.arbiter.toBase58()).to.equal(newArbiter.publicKey.toBase58());
+  });
+
+  it("Slashes the bond of an arbiter replaced after the timeout", async () => {
+    const bondedBetId = new anchor.BN(23);
+    const [bondedBetPda] = deriveBetPda(bondedBetId);
+    const [arbiterBondPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("arbiter_bond"), bondedBetPda.toBuffer()],
+      program.programId
+    );
+    const newArbiter = anchor.web3.Keypair.generate();
+
+    // arbiter_timeout falls halfway between resolve_ts and resolve_deadline
+    const now = nowTs();
+    const resolveTs = now + 10;
+    const arbiterTimeout = now + 15;
+    await createBet(bondedBetId, {
+      deadlineDuel: new anchor.BN(now + 8),
+      deadlineCrowd: new anchor.BN(now + 9),
+      resolveTs: new anchor.BN(resolveTs),
+      resolveDeadline: new anchor.BN(now + 20),
+    });
+
+    const bondAmount = new anchor.BN(300_000_000); // 0.3 SOL
+    await program.methods
+      .setArbiterBond(bondAmount)
+      .accounts({
+        payer: provider.wallet.publicKey,
+        bet: bondedBetPda,
+      })
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: bondedBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .postArbiterBond()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: bondedBetPda,
+        config: configPda,
+        arbiterBond: arbiterBondPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const reassign = () =>
+      program.methods
+        .reassignArbiter(newArbiter.publicKey)
+        .accounts({
+          userA: userA.publicKey,
+          userB: userB.publicKey,
+          arbiter: arbiter.publicKey,
+          bet: bondedBetPda,
+          arbiterBond: arbiterBondPda,
+        })
+        .signers([userA, userB])
+        .rpc();
+
+    // The arbiter still has until the timeout to resolve
+    await sleepUntil(resolveTs);
+    try {
+      await reassign();
+      expect.fail("Duelists alone should not reassign before the timeout");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ArbiterTimeoutNotReached");
+    }
+
+    await sleepUntil(arbiterTimeout);
+
+    const userABalanceBefore = await provider.connection.getBalance(userA.publicKey);
+    const userBBalanceBefore = await provider.connection.getBalance(userB.publicKey);
+
+    await reassign();
+
+    // The silent arbiter's bond is split between the duelists
+    const half = bondAmount.divn(2).toNumber();
+    const userABalanceAfter = await provider.connection.getBalance(userA.publicKey);
+    const userBBalanceAfter = await provider.connection.getBalance(userB.publicKey);
+    expect(userABalanceAfter - userABalanceBefore).to.equal(half);
+    expect(userBBalanceAfter - userBBalanceBefore).to.equal(bondAmount.toNumber() - half);
+    expect(await provider.connection.getAccountInfo(arbiterBondPda)).to.be.null;
+
+    // The new arbiter takes over and has to post a bond of their own
+    const betAccount = await program.account.bet.fetch(bondedBetPda);
+    expect(betAccount.arbiter.toBase58()).to.equal(newArbiter.publicKey.toBase58());
+    expect(betAccount.arbiterBondPosted).to.be.false;
+  });
+
+  it("Splits a slashed bond between the duelists and refunds the crowd in full", async () => {
+    const expiredBetId = new anchor.BN(24);
+    const [expiredBetPda] = deriveBetPda(expiredBetId);
+    const [arbiterBondPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("arbiter_bond"), expiredBetPda.toBuffer()],
+      program.programId
+    );
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        expiredBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    const now = nowTs();
+    const resolveDeadline = now + 14;
+    await createBet(expiredBetId, {
+      deadlineDuel: new anchor.BN(now + 8),
+      deadlineCrowd: new anchor.BN(now + 10),
+      resolveTs: new anchor.BN(now + 12),
+      resolveDeadline: new anchor.BN(resolveDeadline),
+    });
+
+    const bondAmount = new anchor.BN(300_000_000); // 0.3 SOL
+    await program.methods
+      .setArbiterBond(bondAmount)
+      .accounts({
+        payer: provider.wallet.publicKey,
+        bet: expiredBetPda,
+      })
+      .rpc();
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: expiredBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .postArbiterBond()
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: expiredBetPda,
+        config: configPda,
+        arbiterBond: arbiterBondPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: expiredBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    // The arbiter never resolves
+    await sleepUntil(resolveDeadline);
+    await program.methods
+      .expireBet()
+      .accounts({ caller: provider.wallet.publicKey, bet: expiredBetPda })
+      .rpc();
+
+    const userABalanceBefore = await provider.connection.getBalance(userA.publicKey);
+    const userBBalanceBefore = await provider.connection.getBalance(userB.publicKey);
+
+    await program.methods
+      .slashArbiterBond()
+      .accounts({
+        caller: provider.wallet.publicKey,
+        bet: expiredBetPda,
+        arbiterBond: arbiterBondPda,
+        arbiter: arbiter.publicKey,
+        userA: userA.publicKey,
+        userB: userB.publicKey,
+      })
+      .rpc();
+
+    // Only the duelists share the bond...
+    const half = bondAmount.divn(2).toNumber();
+    const userABalanceAfter = await provider.connection.getBalance(userA.publicKey);
+    const userBBalanceAfter = await provider.connection.getBalance(userB.publicKey);
+    expect(userABalanceAfter - userABalanceBefore).to.equal(half);
+    expect(userBBalanceAfter - userBBalanceBefore).to.equal(bondAmount.toNumber() - half);
+
+    // ...because the crowd gets back everything it paid, spread included
+    const bettor1BalanceBefore = await provider.connection.getBalance(bettor1.publicKey);
+    await program.methods
+      .claimSupport()
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: expiredBetPda,
+        supportPosition: supportPositionPda,
+      })
+      .signers([bettor1])
+      .rpc();
+    const bettor1BalanceAfter = await provider.connection.getBalance(bettor1.publicKey);
+    expect(bettor1BalanceAfter - bettor1BalanceBefore).to.equal(supportAmount.toNumber());
+  });
+
+  it("Declares a draw: both stakes and the crowd's net support go back", async () => {
+    const drawBetId = new anchor.BN(25);
+    const [drawBetPda] = deriveBetPda(drawBetId);
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        drawBetPda.toBuffer(),
+        bettor2.publicKey.toBuffer(),
+        Buffer.from([1]),
+      ],
+      program.programId
+    );
+
+    const timeline = quickTimeline();
+    await createBet(drawBetId, timeline);
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: drawBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .supportBet(1, supportAmount)
+      .accounts({
+        bettor: bettor2.publicKey,
+        bet: drawBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor2])
+      .rpc();
+
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(null)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: drawBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(drawBetPda);
+    expect(betAccount.status).to.deep.equal({ draw: {} });
+
+    // Each duelist withdraws their own stake
+    for (const user of [userA, userB]) {
+      const balanceBefore = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: user.publicKey,
+          bet: drawBetPda,
+        })
+        .signers([user])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(user.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(stakeAmount.toNumber());
+    }
+
+    // The supporter gets the net amount back; the spread stays with its recipients
+    const netSupport = supportAmount.muln(98).divn(100).toNumber(); // 2% spread
+    const bettor2BalanceBefore = await provider.connection.getBalance(bettor2.publicKey);
+    await program.methods
+      .claimSupport()
+      .accounts({
+        bettor: bettor2.publicKey,
+        bet: drawBetPda,
+        supportPosition: supportPositionPda,
+      })
+      .signers([bettor2])
+      .rpc();
+    const bettor2BalanceAfter = await provider.connection.getBalance(bettor2.publicKey);
+    expect(bettor2BalanceAfter - bettor2BalanceBefore).to.equal(netSupport);
+  });
+
+  it("Expires a bet the arbiter never resolved and refunds everyone", async () => {
+    const expiredBetId = new anchor.BN(26);
+    const [expiredBetPda] = deriveBetPda(expiredBetId);
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        expiredBetPda.toBuffer(),
+        bettor2.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    const now = nowTs();
+    const resolveDeadline = now + 10;
+    await createBet(expiredBetId, {
+      deadlineDuel: new anchor.BN(now + 4),
+      deadlineCrowd: new anchor.BN(now + 5),
+      resolveTs: new anchor.BN(now + 6),
+      resolveDeadline: new anchor.BN(resolveDeadline),
+    });
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: expiredBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .supportBet(0, supportAmount)
+      .accounts({
+        bettor: bettor2.publicKey,
+        bet: expiredBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([bettor2])
+      .rpc();
+
+    // Nobody can expire the bet while the arbiter is still in time
+    try {
+      await program.methods
+        .expireBet()
+        .accounts({ caller: provider.wallet.publicKey, bet: expiredBetPda })
+        .rpc();
+      expect.fail("expire_bet should wait for resolve_deadline");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ResolveDeadlineNotReached");
+    }
+
+    await sleepUntil(resolveDeadline);
+    await program.methods
+      .expireBet()
+      .accounts({ caller: provider.wallet.publicKey, bet: expiredBetPda })
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(expiredBetPda);
+    expect(betAccount.status).to.deep.equal({ expired: {} });
+
+    for (const user of [userA, userB]) {
+      const balanceBefore = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: user.publicKey,
+          bet: expiredBetPda,
+        })
+        .signers([user])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(user.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(stakeAmount.toNumber());
+    }
+
+    // The crowd gets the gross amount back: no spread is charged on an expired bet
+    const bettor2BalanceBefore = await provider.connection.getBalance(bettor2.publicKey);
+    await program.methods
+      .claimSupport()
+      .accounts({
+        bettor: bettor2.publicKey,
+        bet: expiredBetPda,
+        supportPosition: supportPositionPda,
+      })
+      .signers([bettor2])
+      .rpc();
+    const bettor2BalanceAfter = await provider.connection.getBalance(bettor2.publicKey);
+    expect(bettor2BalanceAfter - bettor2BalanceBefore).to.equal(supportAmount.toNumber());
+  });
+
+  it("Resolves a three-outcome market to the outcome without a duelist", async () => {
+    const marketBetId = new anchor.BN(27);
+    const [marketBetPda] = deriveBetPda(marketBetId);
+    const supportPositionPda = (bettor: anchor.web3.Keypair, outcome: number) =>
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("support"),
+          marketBetPda.toBuffer(),
+          bettor.publicKey.toBuffer(),
+          Buffer.from([outcome]),
+        ],
+        program.programId
+      )[0];
+
+    // 0 = A wins, 1 = B wins, 2 = neither (e.g. the match is abandoned)
+    const timeline = quickTimeline();
+    await createBet(marketBetId, { ...timeline, outcomeCount: 3 });
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: marketBetPda,
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    const backers: [anchor.web3.Keypair, number][] = [
+      [bettor1, 2],
+      [bettor2, 0],
+    ];
+    for (const [bettor, outcome] of backers) {
+      await program.methods
+        .supportBet(outcome, supportAmount)
+        .accounts({
+          bettor: bettor.publicKey,
+          bet: marketBetPda,
+          config: configPda,
+          supportPosition: supportPositionPda(bettor, outcome),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([bettor])
+        .rpc();
+    }
+
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(2)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: marketBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const betAccount = await program.account.bet.fetch(marketBetPda);
+    expect(betAccount.status).to.deep.equal({ resolved: {} });
+    expect(betAccount.winningOutcome).to.equal(2);
+    expect(betAccount.winnerSide).to.be.null;
+
+    // No duelist won, so each one takes their stake back
+    for (const user of [userA, userB]) {
+      const balanceBefore = await provider.connection.getBalance(user.publicKey);
+      await program.methods
+        .withdrawPrincipal()
+        .accounts({
+          winner: user.publicKey,
+          bet: marketBetPda,
+        })
+        .signers([user])
+        .rpc();
+      const balanceAfter = await provider.connection.getBalance(user.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(stakeAmount.toNumber());
+    }
+
+    // Outcome 2's only backer takes the whole crowd pool
+    const netSupport = supportAmount.muln(98).divn(100).toNumber(); // 2% spread
+    const bettor1BalanceBefore = await provider.connection.getBalance(bettor1.publicKey);
+    await program.methods
+      .claimSupport()
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: marketBetPda,
+        supportPosition: supportPositionPda(bettor1, 2),
+      })
+      .signers([bettor1])
+      .rpc();
+    const bettor1BalanceAfter = await provider.connection.getBalance(bettor1.publicKey);
+    expect(bettor1BalanceAfter - bettor1BalanceBefore).to.equal(2 * netSupport);
+  });
+
+  it("Runs a bet in an SPL token through the bet's vault", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const tokenBetId = new anchor.BN(28);
+    const [tokenBetPda] = deriveBetPda(tokenBetId);
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        tokenBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([0]),
+      ],
+      program.programId
+    );
+
+    const tokenStake = new anchor.BN(1_000_000); // 1 token, 6 decimals
+    const tokenSupport = new anchor.BN(500_000);
+    const mint = await createMint(provider.connection, payer, payer.publicKey, null, 6);
+    const tokenAccountsByOwner = new Map<string, anchor.web3.PublicKey>();
+    for (const user of [userA, userB, bettor1]) {
+      const account = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mint,
+        user.publicKey
+      );
+      await mintTo(provider.connection, payer, mint, account.address, payer, 10_000_000);
+      tokenAccountsByOwner.set(user.publicKey.toBase58(), account.address);
+    }
+    const tokenAccountOf = (user: anchor.web3.Keypair) =>
+      tokenAccountsByOwner.get(user.publicKey.toBase58());
+    const tokenBalance = async (user: anchor.web3.Keypair) =>
+      Number((await getAccount(provider.connection, tokenAccountOf(user))).amount);
+    const accounts = tokenAccounts(tokenBetPda, mint, TOKEN_PROGRAM_ID);
+
+    const timeline = quickTimeline();
+    await createBet(
+      tokenBetId,
+      { ...timeline, stakeA: tokenStake, stakeB: tokenStake },
+      { accounts }
+    );
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: tokenBetPda,
+          config: configPda,
+          participantToken: tokenAccountOf(user),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...accounts,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .supportBet(0, tokenSupport)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: tokenBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        bettorToken: tokenAccountOf(bettor1),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...accounts,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    // Stakes and support sit in the vault, not in the bet account
+    const vault = await getAccount(provider.connection, accounts.vault);
+    expect(Number(vault.amount)).to.equal(
+      tokenStake.muln(2).add(tokenSupport).toNumber()
+    );
+
+    await sleepUntil(timeline.resolveTs.toNumber());
+
+    await program.methods
+      .declareWinner(0)
+      .accounts({
+        arbiter: arbiter.publicKey,
+        bet: tokenBetPda,
+      })
+      .signers([arbiter])
+      .rpc();
+
+    const userABalanceBefore = await tokenBalance(userA);
+    await program.methods
+      .withdrawPrincipal()
+      .accounts({
+        winner: userA.publicKey,
+        bet: tokenBetPda,
+        winnerToken: tokenAccountOf(userA),
+        ...accounts,
+      })
+      .signers([userA])
+      .rpc();
+    expect((await tokenBalance(userA)) - userABalanceBefore).to.equal(
+      tokenStake.muln(2).toNumber()
+    );
+
+    // The only backer of A takes back the net support
+    const bettor1BalanceBefore = await tokenBalance(bettor1);
+    await program.methods
+      .claimSupport()
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: tokenBetPda,
+        supportPosition: supportPositionPda,
+        bettorToken: tokenAccountOf(bettor1),
+        ...accounts,
+      })
+      .signers([bettor1])
+      .rpc();
+    expect((await tokenBalance(bettor1)) - bettor1BalanceBefore).to.equal(
+      tokenSupport.muln(98).divn(100).toNumber() // 2% spread
+    );
+  });
+
+  it("Credits the amount net of a Token-2022 transfer fee", async () => {
+    const payer = (provider.wallet as anchor.Wallet).payer;
+    const feeBetId = new anchor.BN(29);
+    const [feeBetPda] = deriveBetPda(feeBetId);
+    const [supportPositionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("support"),
+        feeBetPda.toBuffer(),
+        bettor1.publicKey.toBuffer(),
+        Buffer.from([1]),
+      ],
+      program.programId
+    );
+
+    // Token-2022 mint charging 1% on every transfer
+    const feeBps = 100;
+    const mintKeypair = anchor.web3.Keypair.generate();
+    const mint = mintKeypair.publicKey;
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: payer.publicKey,
+          newAccountPubkey: mint,
+          space: mintLen,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(mintLen),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+          mint,
+          payer.publicKey,
+          payer.publicKey,
+          feeBps,
+          BigInt(1_000_000_000),
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(mint, 6, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+      ),
+      [mintKeypair]
+    );
+
+    const tokenAccountsByOwner = new Map<string, anchor.web3.PublicKey>();
+    for (const user of [userA, userB, bettor1]) {
+      const account = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        mint,
+        user.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await mintTo(
+        provider.connection,
+        payer,
+        mint,
+        account.address,
+        payer,
+        10_000_000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      tokenAccountsByOwner.set(user.publicKey.toBase58(), account.address);
+    }
+    const tokenAccountOf = (user: anchor.web3.Keypair) =>
+      tokenAccountsByOwner.get(user.publicKey.toBase58());
+    const accounts = tokenAccounts(feeBetPda, mint, TOKEN_2022_PROGRAM_ID);
+
+    const tokenStake = new anchor.BN(1_000_000);
+    const tokenSupport = new anchor.BN(500_000);
+    const afterFee = (amount: anchor.BN) => amount.sub(amount.muln(feeBps).divn(10_000));
+
+    await createBet(feeBetId, { stakeA: tokenStake, stakeB: tokenStake }, { accounts });
+
+    for (const user of [userA, userB]) {
+      await program.methods
+        .depositParticipant()
+        .accounts({
+          participant: user.publicKey,
+          bet: feeBetPda,
+          config: configPda,
+          participantToken: tokenAccountOf(user),
+          systemProgram: anchor.web3.SystemProgram.programId,
+          ...accounts,
+        })
+        .signers([user])
+        .rpc();
+    }
+
+    await program.methods
+      .supportBet(1, tokenSupport)
+      .accounts({
+        bettor: bettor1.publicKey,
+        bet: feeBetPda,
+        config: configPda,
+        supportPosition: supportPositionPda,
+        bettorToken: tokenAccountOf(bettor1),
+        systemProgram: anchor.web3.SystemProgram.programId,
+        ...accounts,
+      })
+      .signers([bettor1])
+      .rpc();
+
+    // Each side is credited what reached the vault, not the nominal stake
+    const betAccount = await program.account.bet.fetch(feeBetPda);
+    const netStake = afterFee(tokenStake);
+    expect(betAccount.principalA.toString()).to.equal(netStake.toString());
+    expect(betAccount.principalB.toString()).to.equal(netStake.toString());
+
+    // The spread is taken from the support that arrived, after the transfer fee
+    const receivedSupport = afterFee(tokenSupport);
+    const position = await program.account.supportPosition.fetch(supportPositionPda);
+    expect(position.grossAmount.toString()).to.equal(receivedSupport.toString());
+    expect(betAccount.netSupport[1].toString()).to.equal(
+      receivedSupport.muln(98).divn(100).toString() // 2% spread
+    );
+
+    const vault = await getAccount(
+      provider.connection,
+      accounts.vault,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(vault.amount)).to.equal(netStake.muln(2).add(receivedSupport).toNumber());
   });
 });
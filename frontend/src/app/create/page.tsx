@@ -4,7 +4,7 @@ import React, { useState } from "react";
 import { useRouter } from "next/navigation";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { getProgram, getBetPDA, solToLamports } from "@/lib/anchorClient";
+import { getProgram, getBetPDA, getConfigPDA, solToLamports } from "@/lib/anchorClient";
 import * as anchor from "@coral-xyz/anchor";
 import toast from "react-hot-toast";
 import { useWalletConnection } from "@/lib/useWalletConnection";
@@ -97,6 +97,8 @@ export default function CreateBet() {
         now + Math.round(hoursCrowd * 3600)
       );
       const resolveTs = new anchor.BN(now + Math.round(hoursResolve * 3600));
+      // The arbiter gets 24h after resolveTs before the bet can be expired
+      const resolveDeadline = resolveTs.add(new anchor.BN(24 * 3600));
 
       let stakeLamports: anchor.BN;
       try {
@@ -147,20 +149,10 @@ export default function CreateBet() {
       const sideAName = formData.sideAName.trim() || "Side A";
       const sideBName = formData.sideBName.trim() || "Side B";
 
-      // Get PDAs
-      const [betPda] = getBetPDA(arbiterPubkey, userA, userB);
-
-      // Protocol treasury: use env if provided, otherwise creator wallet as fallback
-      let protocolTreasury: PublicKey;
-      try {
-        const protocolTreasuryStr = (
-          process.env.NEXT_PUBLIC_PROTOCOL_TREASURY || userWalletPubkey.toBase58()
-        ).trim();
-        protocolTreasury = new PublicKey(protocolTreasuryStr);
-      } catch (err) {
-        toast.error("Invalid protocol treasury address");
-        return;
-      }
+      // Get PDAs: the bet id only has to be unique among userA's bets
+      const betId = new anchor.BN(Date.now());
+      const [betPda] = getBetPDA(userA, betId);
+      const [configPda] = getConfigPDA();
 
       const anchorWallet = wallet as unknown as anchor.Wallet;
 
@@ -172,14 +164,26 @@ export default function CreateBet() {
         return;
       }
 
+      // Protocol treasury is set by the admin in the config account
+      let protocolTreasury: PublicKey;
+      try {
+        const config = await (program as any).account.protocolConfig.fetch(configPda);
+        protocolTreasury = config.treasury;
+      } catch (err) {
+        toast.error("Protocol config not initialized");
+        return;
+      }
+
       debugPage.log("createBet args", {
         userA: userA?.toBase58?.(),
         userB: userB?.toBase58?.(),
         arbiter: arbiterPubkey?.toBase58?.(),
+        betId: betId.toString(),
         stakeLamports: stakeLamports?.toString?.(),
         deadlineDuel: deadlineDuel?.toString?.(),
         deadlineCrowd: deadlineCrowd?.toString?.(),
         resolveTs: resolveTs?.toString?.(),
+        resolveDeadline: resolveDeadline.toString(),
         spreadBps,
         creatorShareBps,
         arbiterShareBps,
@@ -187,28 +191,32 @@ export default function CreateBet() {
         accounts: {
           payer: wallet.publicKey?.toBase58?.(),
           bet: betPda?.toBase58?.(),
+          config: configPda.toBase58(),
           protocolTreasury: protocolTreasury?.toBase58?.(),
           systemProgram: SystemProgram.programId.toBase58(),
         },
       });
 
       const tx = await (program as any).methods
-        .createBet(
-          userA,
-          userB,
-          arbiterPubkey,
-          stakeLamports,
+        .createBet(userA, userB, arbiterPubkey, betId, {
+          stakeA: stakeLamports,
+          stakeB: stakeLamports,
+          outcomeCount: 2, // A or B
           deadlineDuel,
           deadlineCrowd,
           resolveTs,
+          resolveDeadline,
           spreadBps,
           creatorShareBps,
           arbiterShareBps,
-          protocolShareBps
-        )
+          protocolShareBps,
+          allowlist: [],
+          arbiterRequirement: null,
+        })
         .accounts({
           payer: wallet.publicKey,
           bet: betPda,
+          config: configPda,
           protocolTreasury,
           systemProgram: SystemProgram.programId,
         })
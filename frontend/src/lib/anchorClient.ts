@@ -7,6 +7,8 @@ const ACCOUNT_DISCRIMINATORS: Record<string, number[]> = {
   bet: [147, 23, 35, 59, 15, 75, 155, 32],
   SupportPosition: [202, 124, 14, 86, 154, 235, 215, 186],
   supportPosition: [202, 124, 14, 86, 154, 235, 215, 186],
+  ProtocolConfig: [207, 91, 250, 28, 152, 179, 215, 209],
+  protocolConfig: [207, 91, 250, 28, 152, 179, 215, 209],
 };
 
 export const PROGRAM_ID = new PublicKey(
@@ -278,23 +280,26 @@ export async function getProgram(
   }
 }
 
-// Helper: Derive Bet PDA
+// Helper: Derive Bet PDA (challenger + per-challenger bet id nonce)
 export function getBetPDA(
-  arbiter: PublicKey,
   userA: PublicKey,
-  userB: PublicKey
+  betId: BN
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from("bet"),
-      arbiter.toBuffer(),
       userA.toBuffer(),
-      userB.toBuffer(),
+      betId.toArrayLike(Buffer, "le", 8),
     ],
     PROGRAM_ID
   );
 }
 
+// Helper: Derive ProtocolConfig PDA
+export function getConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("config")], PROGRAM_ID);
+}
+
 // Helper: Derive SupportPosition PDA
 export function getSupportPositionPDA(
   bet: PublicKey,
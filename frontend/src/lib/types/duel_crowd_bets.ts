@@ -7,21 +7,21 @@ export type DuelCrowdBets = {
       accounts: [
         { name: "payer"; isMut: true; isSigner: true },
         { name: "bet"; isMut: true; isSigner: false },
+        { name: "config"; isMut: false; isSigner: false },
         { name: "protocolTreasury"; isMut: false; isSigner: false },
+        { name: "arbiterProfile"; isMut: false; isSigner: false; isOptional: true },
+        { name: "arbiterSigner"; isMut: false; isSigner: true; isOptional: true },
+        { name: "mint"; isMut: false; isSigner: false; isOptional: true },
+        { name: "vault"; isMut: true; isSigner: false; isOptional: true },
+        { name: "tokenProgram"; isMut: false; isSigner: false; isOptional: true },
         { name: "systemProgram"; isMut: false; isSigner: false }
       ];
       args: [
         { name: "userA"; type: "publicKey" },
         { name: "userB"; type: "publicKey" },
         { name: "arbiter"; type: "publicKey" },
-        { name: "stakeLamports"; type: "u64" },
-        { name: "deadlineDuel"; type: "i64" },
-        { name: "deadlineCrowd"; type: "i64" },
-        { name: "resolveTs"; type: "i64" },
-        { name: "spreadBps"; type: "u16" },
-        { name: "creatorShareBps"; type: "u16" },
-        { name: "arbiterShareBps"; type: "u16" },
-        { name: "protocolShareBps"; type: "u16" }
+        { name: "betId"; type: "u64" },
+        { name: "params"; type: { defined: "CreateBetParams" } }
       ];
     }
   ];
@@ -33,15 +33,24 @@ export type DuelCrowdBets = {
         fields: [
           { name: "userA"; type: "publicKey" },
           { name: "userB"; type: "publicKey" },
+          { name: "allowlist"; type: { vec: "publicKey" } },
           { name: "arbiter"; type: "publicKey" },
-          { name: "stakeLamports"; type: "u64" },
+          { name: "betId"; type: "u64" },
+          { name: "mint"; type: "publicKey" },
+          { name: "stakeA"; type: "u64" },
+          { name: "stakeB"; type: "u64" },
           { name: "userADeposited"; type: "bool" },
           { name: "userBDeposited"; type: "bool" },
+          { name: "principalA"; type: "u64" },
+          { name: "principalB"; type: "u64" },
+          { name: "teamA"; type: { vec: { defined: "TeamMember" } } },
+          { name: "teamB"; type: { vec: { defined: "TeamMember" } } },
           { name: "deadlineDuel"; type: "i64" },
           { name: "deadlineCrowd"; type: "i64" },
           { name: "resolveTs"; type: "i64" },
-          { name: "netSupportA"; type: "u64" },
-          { name: "netSupportB"; type: "u64" },
+          { name: "resolveDeadline"; type: "i64" },
+          { name: "outcomeCount"; type: "u8" },
+          { name: "netSupport"; type: { array: ["u64", 8] } },
           { name: "spreadPoolCreators"; type: "u64" },
           { name: "spreadPoolArbiter"; type: "u64" },
           { name: "spreadPoolProtocol"; type: "u64" },
@@ -51,7 +60,36 @@ export type DuelCrowdBets = {
           { name: "protocolShareBps"; type: "u16" },
           { name: "status"; type: { defined: "BetStatus" } },
           { name: "winnerSide"; type: { option: { defined: "Side" } } },
+          { name: "winningOutcome"; type: { option: "u8" } },
+          { name: "seriesLength"; type: "u8" },
+          { name: "winsA"; type: "u8" },
+          { name: "winsB"; type: "u8" },
+          { name: "fallbackArbiter"; type: "publicKey" },
+          { name: "disputeWindow"; type: "i64" },
+          { name: "disputeBond"; type: "u64" },
+          { name: "disputeEndsAt"; type: "i64" },
+          { name: "disputer"; type: "publicKey" },
+          { name: "disputeBondPosted"; type: "u64" },
+          { name: "disputeStatus"; type: { defined: "DisputeStatus" } },
+          { name: "committee"; type: { vec: { defined: "CommitteeMember" } } },
+          { name: "committeeThreshold"; type: "u8" },
+          { name: "oracleFeed"; type: "publicKey" },
+          { name: "strikePrice"; type: "i64" },
+          { name: "strikeExpo"; type: "i32" },
+          { name: "priceComparison"; type: { defined: "PriceComparison" } },
+          { name: "maxPriceAge"; type: "i64" },
+          { name: "maxConfidenceBps"; type: "u16" },
+          { name: "attestor"; type: "publicKey" },
+          { name: "arbiterBond"; type: "u64" },
+          { name: "arbiterBondPosted"; type: "bool" },
+          { name: "arbiterBondSettled"; type: "bool" },
+          { name: "settlementClaims"; type: "u8" },
+          { name: "openPositions"; type: "u32" },
           { name: "protocolTreasury"; type: "publicKey" },
+          { name: "payer"; type: "publicKey" },
+          { name: "tournament"; type: "publicKey" },
+          { name: "arbiterProfile"; type: "publicKey" },
+          { name: "matchIndex"; type: "u8" },
           { name: "bump"; type: "u8" }
         ];
       };
@@ -63,8 +101,9 @@ export type DuelCrowdBets = {
         fields: [
           { name: "bet"; type: "publicKey" },
           { name: "bettor"; type: "publicKey" },
-          { name: "side"; type: { defined: "Side" } },
+          { name: "outcome"; type: "u8" },
           { name: "netAmount"; type: "u64" },
+          { name: "grossAmount"; type: "u64" },
           { name: "claimed"; type: "bool" },
           { name: "bump"; type: "u8" }
         ];
@@ -72,6 +111,64 @@ export type DuelCrowdBets = {
     }
   ];
   types: [
+    {
+      name: "CreateBetParams";
+      type: {
+        kind: "struct";
+        fields: [
+          { name: "stakeA"; type: "u64" },
+          { name: "stakeB"; type: "u64" },
+          { name: "outcomeCount"; type: "u8" },
+          { name: "deadlineDuel"; type: "i64" },
+          { name: "deadlineCrowd"; type: "i64" },
+          { name: "resolveTs"; type: "i64" },
+          { name: "resolveDeadline"; type: "i64" },
+          { name: "spreadBps"; type: "u16" },
+          { name: "creatorShareBps"; type: "u16" },
+          { name: "arbiterShareBps"; type: "u16" },
+          { name: "protocolShareBps"; type: "u16" },
+          { name: "allowlist"; type: { vec: "publicKey" } },
+          { name: "arbiterRequirement"; type: { option: { defined: "ArbiterRequirement" } } }
+        ];
+      };
+    },
+    {
+      name: "ArbiterRequirement";
+      type: {
+        kind: "struct";
+        fields: [
+          { name: "minResolved"; type: "u64" },
+          { name: "minOnTimeBps"; type: "u16" },
+          { name: "maxOverturnedBps"; type: "u16" }
+        ];
+      };
+    },
+    {
+      name: "TeamMember";
+      type: {
+        kind: "struct";
+        fields: [
+          { name: "member"; type: "publicKey" },
+          { name: "stake"; type: "u64" },
+          { name: "principal"; type: "u64" },
+          { name: "deposited"; type: "bool" },
+          { name: "principalClaimed"; type: "bool" },
+          { name: "spreadClaimed"; type: "bool" }
+        ];
+      };
+    },
+    {
+      name: "CommitteeMember";
+      type: {
+        kind: "struct";
+        fields: [
+          { name: "member"; type: "publicKey" },
+          { name: "voted"; type: "bool" },
+          { name: "vote"; type: { option: "u8" } },
+          { name: "feeClaimed"; type: "bool" }
+        ];
+      };
+    },
     {
       name: "BetStatus";
       type: {
@@ -79,7 +176,9 @@ export type DuelCrowdBets = {
         variants: [
           { name: "Open" },
           { name: "Resolved" },
-          { name: "Cancelled" }
+          { name: "Cancelled" },
+          { name: "Expired" },
+          { name: "Draw" }
         ];
       };
     },
@@ -89,6 +188,26 @@ export type DuelCrowdBets = {
         kind: "enum";
         variants: [{ name: "A" }, { name: "B" }];
       };
+    },
+    {
+      name: "DisputeStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          { name: "None" },
+          { name: "Open" },
+          { name: "Upheld" },
+          { name: "Overturned" },
+          { name: "Lapsed" }
+        ];
+      };
+    },
+    {
+      name: "PriceComparison";
+      type: {
+        kind: "enum";
+        variants: [{ name: "Above" }, { name: "Below" }];
+      };
     }
   ];
   events: [];
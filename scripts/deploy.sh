@@ -51,13 +51,17 @@ if [ "$NETWORK" = "devnet" ]; then
     fi
 fi
 
-# Build program
+# Build program (mainnet builds only accept the mainnet Pyth program as oracle owner)
 echo "🔨 Building program..."
-anchor build
+if [ "$NETWORK" = "mainnet-beta" ]; then
+    anchor build --program-name duel_crowd_bets -- --features mainnet
+else
+    anchor build --program-name duel_crowd_bets
+fi
 
-# Deploy program
+# Deploy program (mock_pyth is a localnet-only test program)
 echo "🚀 Deploying program to $NETWORK..."
-PROGRAM_OUTPUT=$(anchor deploy 2>&1)
+PROGRAM_OUTPUT=$(anchor deploy --program-name duel_crowd_bets 2>&1)
 echo "$PROGRAM_OUTPUT"
 
 # Extract program ID